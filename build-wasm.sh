@@ -0,0 +1,19 @@
+#!/bin/bash
+set -e
+
+if ! command -v wasm-bindgen &> /dev/null; then
+    echo "❌ Требуется 'wasm-bindgen'. Установи: cargo install wasm-bindgen-cli"
+    exit 1
+fi
+
+DIST_DIR="bin/wasm"
+mkdir -p "$DIST_DIR"
+
+echo "🕸️ Building for WebAssembly (wasm32-unknown-unknown)..."
+rustup target add wasm32-unknown-unknown &>/dev/null || true
+cargo build --release --lib --target wasm32-unknown-unknown --no-default-features --features wasm,full
+wasm-bindgen --target web --out-dir "$DIST_DIR" "target/wasm32-unknown-unknown/release/jass.wasm"
+
+echo ""
+echo "✅ Build complete:"
+ls -lh "$DIST_DIR"
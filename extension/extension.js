@@ -69,7 +69,10 @@ module.exports = {
             options,
             {
                 progressOnInitialization: true,
-                initializationOptions: {},
+                initializationOptions: workspace.getConfiguration('jass'),
+                synchronize: {
+                    configurationSection: 'jass'
+                },
                 documentSelector: [
                     {scheme: 'file', language: 'lua'},
                     {scheme: 'file', language: 'vjass'},
@@ -0,0 +1,27 @@
+; https://tree-sitter.github.io/tree-sitter/3-syntax-highlighting.html
+
+(comment) @comment
+
+(section
+  name: (name) @namespace.declaration)
+
+(item
+  key: (key) @property.declaration)
+
+(number) @number
+
+(boolean) @boolean
+
+(rawcode) @constant
+
+[
+  (string)
+  (text)
+] @string
+
+[
+  "["
+  "]"
+  "="
+  ","
+] @operator
@@ -0,0 +1,365 @@
+use crate::cli::{collect_files, file_uri, read_document, take_option};
+use jass::lsp::diagnostic::{Diagnostic, DiagnosticSeverity};
+use jass::lsp::position::Position;
+use jass::lsp::range::Range;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: check [--format human|json|sarif|checkstyle] <paths...>";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Human,
+    Json,
+    Sarif,
+    Checkstyle,
+}
+
+struct FileReport {
+    path: PathBuf,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// `check <paths...>`: prints diagnostics, returns process exit code.
+/// 0 - clean, 1 - there are errors, 2 - bad arguments.
+pub fn run(mut args: Vec<String>) -> i32 {
+    let format = match take_option(&mut args, "--format").as_deref() {
+        None | Some("human") => Format::Human,
+        Some("json") => Format::Json,
+        Some("sarif") => Format::Sarif,
+        Some("checkstyle") => Format::Checkstyle,
+        Some(other) => {
+            eprintln!("Unknown format: {other}\n{USAGE}");
+            return 2;
+        }
+    };
+
+    if args.is_empty() || args.iter().any(|a| a.starts_with("--")) {
+        eprintln!("{USAGE}");
+        return 2;
+    }
+
+    let reports: Vec<FileReport> = collect_files(&args).into_iter().map(check).collect();
+
+    let output = match format {
+        Format::Human => human(&reports),
+        Format::Json => serde_json::to_string_pretty(&json_report(&reports)).unwrap(),
+        Format::Sarif => serde_json::to_string_pretty(&sarif(&reports)).unwrap(),
+        Format::Checkstyle => checkstyle(&reports),
+    };
+    if !output.is_empty() {
+        println!("{output}");
+    }
+
+    let failed = reports.iter().any(|r| {
+        r.diagnostics
+            .iter()
+            .any(|d| d.severity == Some(DiagnosticSeverity::Error))
+    });
+    if failed {
+        1
+    } else {
+        0
+    }
+}
+
+fn file_error(message: String) -> Vec<Diagnostic> {
+    let start = Position {
+        line: 0,
+        character: 0,
+    };
+    vec![Diagnostic {
+        range: Range { start, end: start },
+        severity: Some(DiagnosticSeverity::Error),
+        code: None,
+        source: None,
+        message,
+        related_information: None,
+    }]
+}
+
+fn check(path: PathBuf) -> FileReport {
+    let diagnostics = match read_document(&path) {
+        Ok(document) => document.diagnostics(&file_uri(&path)),
+        Err(message) => file_error(message),
+    };
+    FileReport { path, diagnostics }
+}
+
+fn severity_name(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::Warning) => "warning",
+        Some(DiagnosticSeverity::Information) => "info",
+        Some(DiagnosticSeverity::Hint) => "hint",
+        _ => "error",
+    }
+}
+
+fn human(reports: &[FileReport]) -> String {
+    let mut lines = Vec::new();
+    for report in reports {
+        for d in &report.diagnostics {
+            let source = d
+                .source
+                .as_ref()
+                .map_or(String::new(), |s| format!(" [{s}]"));
+            lines.push(format!(
+                "{}:{}:{}: {}: {}{}",
+                report.path.display(),
+                d.range.start.line + 1,
+                d.range.start.character + 1,
+                severity_name(d.severity),
+                d.message,
+                source
+            ));
+            for related in d.related_information.iter().flatten() {
+                lines.push(format!(
+                    "  {}:{}:{}: note: {}",
+                    report.path.display(),
+                    related.location.range.start.line + 1,
+                    related.location.range.start.character + 1,
+                    related.message
+                ));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+fn json_report(reports: &[FileReport]) -> Value {
+    let mut items = Vec::new();
+    for report in reports {
+        for d in &report.diagnostics {
+            items.push(json!({
+                "file": report.path.display().to_string(),
+                "line": d.range.start.line + 1,
+                "column": d.range.start.character + 1,
+                "endLine": d.range.end.line + 1,
+                "endColumn": d.range.end.character + 1,
+                "severity": severity_name(d.severity),
+                "source": d.source,
+                "code": d.code,
+                "message": d.message,
+            }));
+        }
+    }
+    Value::Array(items)
+}
+
+/// https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+fn sarif(reports: &[FileReport]) -> Value {
+    let mut results = Vec::new();
+    for report in reports {
+        for d in &report.diagnostics {
+            let level = match d.severity {
+                Some(DiagnosticSeverity::Warning) => "warning",
+                Some(DiagnosticSeverity::Information) | Some(DiagnosticSeverity::Hint) => "note",
+                _ => "error",
+            };
+            results.push(json!({
+                "ruleId": d.code.as_deref().or(d.source.as_deref()).unwrap_or("file"),
+                "level": level,
+                "message": { "text": d.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": report.path.display().to_string().replace('\\', "/"),
+                        },
+                        "region": {
+                            "startLine": d.range.start.line + 1,
+                            "startColumn": d.range.start.character + 1,
+                            "endLine": d.range.end.line + 1,
+                            "endColumn": d.range.end.character + 1,
+                        },
+                    },
+                }],
+            }));
+        }
+    }
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/WarRaft/JASS-Tree-sitter-Rust",
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// https://checkstyle.org/ XML report, understood by most CI annotators.
+fn checkstyle(reports: &[FileReport]) -> String {
+    let mut xml = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+        r#"<checkstyle version="4.3">"#.to_string(),
+    ];
+    for report in reports {
+        xml.push(format!(
+            r#"  <file name="{}">"#,
+            xml_escape(&report.path.display().to_string())
+        ));
+        for d in &report.diagnostics {
+            xml.push(format!(
+                r#"    <error line="{}" column="{}" severity="{}" message="{}" source="{}"/>"#,
+                d.range.start.line + 1,
+                d.range.start.character + 1,
+                severity_name(d.severity),
+                xml_escape(&d.message),
+                xml_escape(d.source.as_deref().unwrap_or(env!("CARGO_PKG_NAME")))
+            ));
+        }
+        xml.push("  </file>".to_string());
+    }
+    xml.push("</checkstyle>".to_string());
+    xml.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jass::lsp::diagnostic::DiagnosticRelatedInformation;
+    use jass::lsp::symbol::Location;
+    use std::{env, fs};
+    use url::Url;
+
+    fn range(line: usize, start: usize, end: usize) -> Range {
+        Range {
+            start: Position {
+                line,
+                character: start,
+            },
+            end: Position {
+                line,
+                character: end,
+            },
+        }
+    }
+
+    /// A warning pointing at an earlier line, and an error without source or code.
+    fn reports() -> Vec<FileReport> {
+        vec![FileReport {
+            path: PathBuf::from("map/units.bni"),
+            diagnostics: vec![
+                Diagnostic {
+                    range: range(2, 0, 4),
+                    severity: Some(DiagnosticSeverity::Warning),
+                    code: Some("duplicate-key".into()),
+                    source: Some("bni".into()),
+                    message: "duplicate key `Name`".into(),
+                    related_information: Some(vec![DiagnosticRelatedInformation {
+                        location: Location {
+                            uri: Url::parse("file:///map/units.bni").unwrap(),
+                            range: range(1, 0, 4),
+                        },
+                        message: "first `Name` is here".into(),
+                    }]),
+                },
+                Diagnostic {
+                    range: range(3, 1, 2),
+                    severity: Some(DiagnosticSeverity::Error),
+                    code: None,
+                    source: None,
+                    message: "a <b> & \"c\"".into(),
+                    related_information: None,
+                },
+            ],
+        }]
+    }
+
+    #[test]
+    fn prints_human_lines_with_notes() {
+        assert_eq!(
+            human(&reports()),
+            "map/units.bni:3:1: warning: duplicate key `Name` [bni]\n  \
+             map/units.bni:2:1: note: first `Name` is here\n\
+             map/units.bni:4:2: error: a <b> & \"c\""
+        );
+    }
+
+    #[test]
+    fn prints_json_with_one_based_ranges() {
+        let json = json_report(&reports());
+        assert_eq!(
+            json[0],
+            json!({
+                "file": "map/units.bni",
+                "line": 3,
+                "column": 1,
+                "endLine": 3,
+                "endColumn": 5,
+                "severity": "warning",
+                "source": "bni",
+                "code": "duplicate-key",
+                "message": "duplicate key `Name`",
+            })
+        );
+        assert_eq!(json[1]["source"], Value::Null);
+    }
+
+    #[test]
+    fn prints_sarif_results() {
+        let sarif = sarif(&reports());
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = &sarif["runs"][0]["results"];
+        assert_eq!(results[0]["ruleId"], "duplicate-key");
+        assert_eq!(results[0]["level"], "warning");
+        assert_eq!(results[1]["ruleId"], "file");
+        assert_eq!(results[1]["level"], "error");
+        let location = &results[1]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "map/units.bni");
+        assert_eq!(
+            location["region"],
+            json!({ "startLine": 4, "startColumn": 2, "endLine": 4, "endColumn": 3 })
+        );
+    }
+
+    #[test]
+    fn prints_escaped_checkstyle() {
+        let xml = checkstyle(&reports());
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle"));
+        assert!(xml.contains(
+            r#"<error line="3" column="1" severity="warning" message="duplicate key `Name`" source="bni"/>"#
+        ));
+        assert!(xml.contains(r#"message="a &lt;b&gt; &amp; &quot;c&quot;""#));
+        assert!(xml.ends_with("  </file>\n</checkstyle>"));
+    }
+
+    #[test]
+    fn exits_with_1_on_errors_only() {
+        let dir = env::temp_dir().join(format!("jass-check-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = |name: &str, text: &str| {
+            let path = dir.join(name);
+            fs::write(&path, text).unwrap();
+            vec![path.display().to_string()]
+        };
+
+        assert_eq!(run(file("clean.bni", "[hfoo]\nName=Footman\n")), 0);
+        assert_eq!(run(file("warned.bni", "[hfoo]\nName=a\nName=b\n")), 0);
+        assert_eq!(run(file("broken.bni", "[hfoo]\nName\n")), 1);
+        assert_eq!(run(file("unknown.txt", "")), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn exits_with_2_on_bad_arguments() {
+        assert_eq!(run(Vec::new()), 2);
+        assert_eq!(run(vec!["--fix".into(), "a.bni".into()]), 2);
+        assert_eq!(run(vec!["--format=xml".into(), "a.bni".into()]), 2);
+    }
+}
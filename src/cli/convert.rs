@@ -0,0 +1,315 @@
+use crate::cli::take_option;
+use jass::lng::bni;
+use jass::util::encoding;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+const USAGE: &str = "Usage: convert [--to bni|json|toml] [--output <path>] <file>";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Bni,
+    Json,
+    Toml,
+}
+
+impl Format {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "bni" => Some(Self::Bni),
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// By file extension.
+    fn of(path: &Path) -> Option<Self> {
+        Self::parse(path.extension()?.to_str()?)
+    }
+}
+
+/// `convert <file>`: BNI to JSON or TOML and back, with the parser of the language server.
+/// The input format is taken from the extension, the output one from `--to`, the
+/// extension of `--output`, or JSON for BNI and BNI otherwise. Prints to stdout without
+/// `--output`. Returns process exit code.
+pub fn run(mut args: Vec<String>) -> i32 {
+    let to = take_option(&mut args, "--to");
+    let output = take_option(&mut args, "--output");
+
+    let [path] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return 2;
+    };
+    if path.starts_with("--") {
+        eprintln!("{USAGE}");
+        return 2;
+    }
+    let path = Path::new(path);
+    let Some(from) = Format::of(path) else {
+        eprintln!("{}: unsupported file type", path.display());
+        return 1;
+    };
+
+    let to = match to {
+        Some(name) => match Format::parse(&name) {
+            Some(format) => format,
+            None => {
+                eprintln!("Unknown format: {name}\n{USAGE}");
+                return 2;
+            }
+        },
+        None => output
+            .as_deref()
+            .and_then(|o| Format::of(Path::new(o)))
+            .unwrap_or(match from {
+                Format::Bni => Format::Json,
+                _ => Format::Bni,
+            }),
+    };
+
+    let text = match convert(path, from, to) {
+        Ok(text) => text,
+        Err(message) => {
+            eprintln!("{}: {message}", path.display());
+            return 1;
+        }
+    };
+    match output {
+        Some(output) => match fs::write(&output, text) {
+            Ok(()) => 0,
+            Err(err) => {
+                eprintln!("{output}: can't write file: {err}");
+                1
+            }
+        },
+        None => {
+            print!("{text}");
+            0
+        }
+    }
+}
+
+fn convert(path: &Path, from: Format, to: Format) -> Result<String, String> {
+    let bytes = fs::read(path).map_err(|err| format!("can't read file: {err}"))?;
+    let text = encoding::decode(&bytes).text;
+
+    let value: Value = match from {
+        Format::Bni => bni::from_str(&text).map_err(|err| err.to_string())?,
+        Format::Json => serde_json::from_str(&text).map_err(|err| err.to_string())?,
+        Format::Toml => toml::from_str(&text).map_err(|err| err.to_string())?,
+    };
+    match to {
+        Format::Bni => bni::to_string(&value).map_err(|err| err.to_string()),
+        Format::Json => serde_json::to_string_pretty(&value)
+            .map(|json| json + "\n")
+            .map_err(|err| err.to_string()),
+        Format::Toml => toml::to_string(&value.tables_last()).map_err(|err| err.to_string()),
+    }
+}
+
+/// Data of any of the formats, with maps in the order they were read, so that
+/// converted files diff against each other.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    List(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Same value with tables after the other keys of each map, as TOML wants them,
+    /// the order within both kept.
+    fn tables_last(self) -> Value {
+        match self {
+            Value::List(list) => Value::List(list.into_iter().map(Value::tables_last).collect()),
+            Value::Map(entries) => {
+                let (tables, mut entries): (Vec<_>, Vec<_>) = entries
+                    .into_iter()
+                    .map(|(key, value)| (key, value.tables_last()))
+                    .partition(|(_, value)| value.is_table());
+                entries.extend(tables);
+                Value::Map(entries)
+            }
+            value => value,
+        }
+    }
+
+    /// Map, or list of maps, written as `[table]` or `[[table]]` in TOML.
+    fn is_table(&self) -> bool {
+        match self {
+            Value::Map(_) => true,
+            Value::List(list) => !list.is_empty() && list.iter().all(Value::is_table),
+            _ => false,
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::Null => serializer.serialize_none(),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::Integer(v) => serializer.serialize_i64(*v),
+            Value::Float(v) => serializer.serialize_f64(*v),
+            Value::String(v) => serializer.serialize_str(v),
+            Value::List(list) => {
+                let mut seq = serializer.serialize_seq(Some(list.len()))?;
+                for element in list {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            }
+            Value::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Integer(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(i64::try_from(v).map_or(Value::Float(v as f64), Value::Integer))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::Float(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut list = Vec::new();
+        while let Some(element) = seq.next_element()? {
+            list.push(element);
+        }
+        Ok(Value::List(list))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(Value::Map(entries))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const BNI: &str = "version=2\n\n[hfoo]\nName=\"Footman, Elite\"\nHp=420\nSpeed=1.5\nFlag=true\nButtonpos=0,2\n";
+
+    /// Converts `text` written to a temporary `name`, which is removed after.
+    fn converted(name: &str, text: &str, to: Format) -> Result<String, String> {
+        let path = env::temp_dir().join(format!("jass-convert-{}-{name}", std::process::id()));
+        fs::write(&path, text).unwrap();
+        let result = convert(&path, Format::of(&path).unwrap(), to);
+        fs::remove_file(&path).unwrap();
+        result
+    }
+
+    #[test]
+    fn converts_bni_to_json_in_order() {
+        let json = converted("units.bni", BNI, Format::Json).unwrap();
+        assert_eq!(
+            json,
+            "{\n  \"version\": 2,\n  \"hfoo\": {\n    \"Name\": \"Footman, Elite\",\n    \"Hp\": 420,\n    \"Speed\": 1.5,\n    \"Flag\": true,\n    \"Buttonpos\": [\n      0,\n      2\n    ]\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn round_trips_through_json_and_toml() {
+        let json = converted("a.bni", BNI, Format::Json).unwrap();
+        let back = converted("a.json", &json, Format::Bni).unwrap();
+        assert_eq!(back, BNI);
+
+        let toml = converted("b.bni", BNI, Format::Toml).unwrap();
+        assert!(toml.contains("Buttonpos = [0, 2]"));
+        let back = converted("b.toml", &toml, Format::Bni).unwrap();
+        assert_eq!(back, BNI);
+    }
+
+    #[test]
+    fn writes_toml_tables_after_keys() {
+        let json =
+            r#"{"hfoo": {"Name": "Footman", "abil": {"Id": "Adef"}, "Hp": 420}, "version": 2}"#;
+        let toml = converted("c.json", json, Format::Toml).unwrap();
+        assert_eq!(
+            toml,
+            "version = 2\n\n[hfoo]\nName = \"Footman\"\nHp = 420\n\n[hfoo.abil]\nId = \"Adef\"\n"
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert!(converted("bad.json", "{ \"a\": ", Format::Bni).is_err());
+    }
+
+    #[test]
+    fn picks_formats_from_names() {
+        assert_eq!(Format::of(Path::new("units.BNI")), Some(Format::Bni));
+        assert_eq!(Format::of(Path::new("units.ini")), None);
+        assert_eq!(Format::parse("Toml"), Some(Format::Toml));
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert_eq!(run(vec![]), 2);
+        assert_eq!(run(vec!["--to".into(), "xml".into(), "a.bni".into()]), 2);
+        assert_eq!(run(vec!["units.ini".into()]), 1);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::cli::{collect_files, take_flag};
+use jass::lng::{backend_for_path, format, Document};
+use jass::util::encoding;
+use jass::util::settings::FormatSettings;
+use std::fs;
+use std::path::Path;
+
+const USAGE: &str =
+    "Usage: fmt [--check] [--align-keys] [--sort-keys] [--space-around-equals] <paths...>";
+
+/// `fmt <paths...>`: formats files in place, returns process exit code.
+/// With `--check` only lists files that would change: 0 - all formatted, 1 - some are not.
+pub fn run(mut args: Vec<String>) -> i32 {
+    let check = take_flag(&mut args, "--check");
+    let settings = FormatSettings {
+        align_keys: take_flag(&mut args, "--align-keys"),
+        sort_keys: take_flag(&mut args, "--sort-keys"),
+        space_around_equals: take_flag(&mut args, "--space-around-equals"),
+    };
+
+    if args.is_empty() || args.iter().any(|a| a.starts_with("--")) {
+        eprintln!("{USAGE}");
+        return 2;
+    }
+
+    let mut code = 0;
+    for path in collect_files(&args) {
+        let formatted = match format_file(&path, &settings) {
+            Ok(Some(formatted)) => formatted,
+            Ok(None) => continue,
+            Err(message) => {
+                eprintln!("{}: {message}", path.display());
+                code = 1;
+                continue;
+            }
+        };
+
+        if check {
+            println!("{}", path.display());
+            code = 1;
+        } else if let Err(err) = fs::write(&path, formatted) {
+            eprintln!("{}: can't write file: {err}", path.display());
+            code = 1;
+        }
+    }
+    code
+}
+
+/// New contents in the encoding and line breaks of the file, `None` if nothing changes.
+fn format_file(path: &Path, settings: &FormatSettings) -> Result<Option<Vec<u8>>, String> {
+    let Some(backend) = backend_for_path(path) else {
+        return Err("unsupported file type".into());
+    };
+    let bytes = fs::read(path).map_err(|err| format!("can't read file: {err}"))?;
+    let decoded = encoding::decode(&bytes);
+
+    let Some(lines) = Document::parse(backend, &decoded.text).format(settings) else {
+        return Ok(None);
+    };
+    let formatted = encoding::encode(&format::join(&lines, &decoded.text), decoded.encoding);
+    Ok((formatted != bytes).then_some(formatted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn checks_then_formats_in_place() {
+        let dir = env::temp_dir().join(format!("jass-fmt-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("units.bni");
+        fs::write(&path, "[ hfoo ]\r\nName = Footman\r\n").unwrap();
+        let args = |check: bool| {
+            let path = path.display().to_string();
+            match check {
+                true => vec!["--check".to_string(), path],
+                false => vec![path],
+            }
+        };
+
+        assert_eq!(run(args(true)), 1);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "[ hfoo ]\r\nName = Footman\r\n"
+        );
+
+        assert_eq!(run(args(false)), 0);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "[hfoo]\r\nName=Footman\r\n"
+        );
+        assert_eq!(run(args(true)), 0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_unknown_flags() {
+        assert_eq!(run(vec!["--tabs".into(), "a.bni".into()]), 2);
+    }
+}
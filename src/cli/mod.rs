@@ -0,0 +1,67 @@
+pub mod check;
+#[cfg(feature = "bni")]
+pub mod convert;
+pub mod fmt;
+pub mod tokens;
+pub mod tree;
+
+use jass::lng::{backend_for_path, source_files, Document};
+use std::fs;
+use std::path::{Path, PathBuf};
+use url::Url;
+
+/// Reads and parses a file with the backend picked by its extension.
+pub fn read_document(path: &Path) -> Result<Document, String> {
+    let Some(backend) = backend_for_path(path) else {
+        return Err("unsupported file type".into());
+    };
+    let bytes = fs::read(path).map_err(|err| format!("can't read file: {err}"))?;
+    Ok(Document::decode(backend, &bytes))
+}
+
+/// `file://` URI of a path given on the command line, what related information points to.
+pub fn file_uri(path: &Path) -> Url {
+    fs::canonicalize(path)
+        .ok()
+        .and_then(|path| Url::from_file_path(path).ok())
+        .unwrap_or_else(|| Url::parse("file:///").unwrap())
+}
+
+/// Files given on the command line, directories expanded to files of known languages.
+pub fn collect_files(paths: &[String]) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    for path in paths {
+        let path = Path::new(path);
+        if path.is_dir() {
+            result.extend(source_files(path));
+        } else {
+            result.push(path.to_path_buf());
+        }
+    }
+    result
+}
+
+/// Value of `--name value` or `--name=value`, removed from `args`.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let prefix = format!("{name}=");
+    let index = args
+        .iter()
+        .position(|a| a == name || a.starts_with(&prefix))?;
+    let arg = args.remove(index);
+    match arg.strip_prefix(&prefix) {
+        Some(value) => Some(value.to_string()),
+        None if index < args.len() => Some(args.remove(index)),
+        None => None,
+    }
+}
+
+/// Whether `--name` was given, removed from `args`.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    match args.iter().position(|a| a == name) {
+        Some(index) => {
+            args.remove(index);
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,142 @@
+use crate::cli::{read_document, take_option};
+use jass::lng::Document;
+use serde_json::{json, Value};
+use std::path::Path;
+
+const USAGE: &str = "Usage: tokens [--format text|json] <file>";
+
+/// `tokens <file>`: semantic tokens as the server sends them, decoded.
+pub fn run(mut args: Vec<String>) -> i32 {
+    let json = match take_option(&mut args, "--format").as_deref() {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(other) => {
+            eprintln!("Unknown format: {other}\n{USAGE}");
+            return 2;
+        }
+    };
+
+    let [path] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return 2;
+    };
+
+    let document = match read_document(Path::new(path)) {
+        Ok(document) => document,
+        Err(message) => {
+            eprintln!("{path}: {message}");
+            return 1;
+        }
+    };
+
+    let rows = rows(&document);
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&Value::Array(rows)).unwrap()
+        );
+        return 0;
+    }
+
+    for row in &rows {
+        println!("{}", text_row(row));
+    }
+    0
+}
+
+/// Tokens in line and column order, with the text they cover.
+fn rows(document: &Document) -> Vec<Value> {
+    let semantic = document.semantic();
+    let mut rows = Vec::new();
+
+    for line in semantic.lines.values() {
+        let mut tokens = line.tokens.clone();
+        tokens.sort_by_key(|t| t.pos);
+
+        for token in tokens {
+            let text = document
+                .line_list
+                .lines
+                .get(token.line)
+                .and_then(|l| l.get(token.pos..token.pos + token.len))
+                .unwrap_or("");
+            let modifiers: Vec<String> = token.modifiers.iter().map(|m| m.to_string()).collect();
+
+            rows.push(json!({
+                "line": token.line,
+                "column": token.pos,
+                "length": token.len,
+                "type": token.token_type.to_string(),
+                "modifiers": modifiers,
+                "text": text,
+            }));
+        }
+    }
+    rows
+}
+
+/// `line:column length type [modifiers] "text"`
+fn text_row(row: &Value) -> String {
+    format!(
+        "{}:{} {} {} [{}] {}",
+        row["line"],
+        row["column"],
+        row["length"],
+        row["type"].as_str().unwrap_or(""),
+        row["modifiers"]
+            .as_array()
+            .map(|m| m
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(","))
+            .unwrap_or_default(),
+        row["text"]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jass::lng::backend;
+
+    /// Columns and lengths are bytes, as the server keeps them.
+    #[test]
+    fn dumps_tokens_in_order_with_text() {
+        let document = Document::parse(backend("bni").unwrap(), "[hfoo]\nName=Пехота\n");
+        let lines: Vec<String> = rows(&document).iter().map(text_row).collect();
+        assert_eq!(
+            lines,
+            [
+                r#"0:0 1 Operator [] "[""#,
+                r#"0:1 4 Namespace [Declaration] "hfoo""#,
+                r#"0:5 1 Operator [] "]""#,
+                r#"1:0 4 Property [Declaration] "Name""#,
+                r#"1:4 1 Operator [] "=""#,
+                r#"1:5 12 String [] "Пехота""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn dumps_json_rows() {
+        let document = Document::parse(backend("bni").unwrap(), "[hfoo]\n");
+        assert_eq!(
+            rows(&document)[1],
+            json!({
+                "line": 0,
+                "column": 1,
+                "length": 4,
+                "type": "Namespace",
+                "modifiers": ["Declaration"],
+                "text": "hfoo",
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_format() {
+        assert_eq!(run(vec!["--format=xml".into(), "a.bni".into()]), 2);
+        assert_eq!(run(Vec::new()), 2);
+    }
+}
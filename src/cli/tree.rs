@@ -0,0 +1,207 @@
+use crate::cli::{read_document, take_option};
+use serde_json::{json, Value};
+use std::path::Path;
+use tree_sitter::{Node, TreeCursor};
+
+const USAGE: &str = "Usage: tree [--format sexp|json] <file>";
+
+/// `tree <file>`: syntax tree with byte and point ranges.
+pub fn run(mut args: Vec<String>) -> i32 {
+    let json = match take_option(&mut args, "--format").as_deref() {
+        None | Some("sexp") => false,
+        Some("json") => true,
+        Some(other) => {
+            eprintln!("Unknown format: {other}\n{USAGE}");
+            return 2;
+        }
+    };
+
+    let [path] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return 2;
+    };
+
+    let document = match read_document(Path::new(path)) {
+        Ok(document) => document,
+        Err(message) => {
+            eprintln!("{path}: {message}");
+            return 1;
+        }
+    };
+
+    let text = document.line_list.to_text();
+    let mut cursor = document.tree.walk();
+
+    if json {
+        let value = node_json(&mut cursor, text.as_bytes());
+        println!("{}", serde_json::to_string_pretty(&value).unwrap());
+    } else {
+        let mut out = String::new();
+        node_sexp(&mut cursor, 0, &mut out);
+        println!("{out}");
+    }
+    0
+}
+
+fn kind(node: &Node) -> String {
+    if node.is_missing() {
+        format!("MISSING {}", node.kind())
+    } else if node.is_named() {
+        node.kind().to_string()
+    } else {
+        format!("{:?}", node.kind())
+    }
+}
+
+/// `(kind [row, column] - [row, column] start..end children)`, like `tree-sitter parse`
+/// plus byte range and anonymous nodes.
+fn node_sexp(cursor: &mut TreeCursor, depth: usize, out: &mut String) {
+    let node = cursor.node();
+    let s = node.start_position();
+    let e = node.end_position();
+
+    if depth > 0 {
+        out.push('\n');
+    }
+    out.push_str(&"  ".repeat(depth));
+    if let Some(field) = cursor.field_name() {
+        out.push_str(field);
+        out.push_str(": ");
+    }
+    out.push_str(&format!(
+        "({} [{}, {}] - [{}, {}] {}..{}",
+        kind(&node),
+        s.row,
+        s.column,
+        e.row,
+        e.column,
+        node.start_byte(),
+        node.end_byte()
+    ));
+
+    if cursor.goto_first_child() {
+        loop {
+            node_sexp(cursor, depth + 1, out);
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+        cursor.goto_parent();
+    }
+    out.push(')');
+}
+
+fn node_json(cursor: &mut TreeCursor, source: &[u8]) -> Value {
+    let node = cursor.node();
+    let s = node.start_position();
+    let e = node.end_position();
+
+    let mut value = json!({
+        "kind": node.kind(),
+        "named": node.is_named(),
+        "startByte": node.start_byte(),
+        "endByte": node.end_byte(),
+        "startPoint": { "row": s.row, "column": s.column },
+        "endPoint": { "row": e.row, "column": e.column },
+    });
+    if let Some(field) = cursor.field_name() {
+        value["field"] = json!(field);
+    }
+    if node.is_error() {
+        value["error"] = json!(true);
+    }
+    if node.is_missing() {
+        value["missing"] = json!(true);
+    }
+
+    if cursor.goto_first_child() {
+        let mut children = Vec::new();
+        loop {
+            children.push(node_json(cursor, source));
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+        cursor.goto_parent();
+        value["children"] = Value::Array(children);
+    } else {
+        value["text"] = json!(node.utf8_text(source).unwrap_or(""));
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jass::lng::{backend, Document};
+
+    fn document(text: &str) -> Document {
+        Document::parse(backend("bni").unwrap(), text)
+    }
+
+    fn sexp(text: &str) -> String {
+        let mut out = String::new();
+        node_sexp(&mut document(text).tree.walk(), 0, &mut out);
+        out
+    }
+
+    fn json(text: &str) -> Value {
+        node_json(&mut document(text).tree.walk(), text.as_bytes())
+    }
+
+    #[test]
+    fn dumps_sexp_with_fields_and_anonymous_nodes() {
+        assert_eq!(
+            sexp("[hfoo]\nName=1\n"),
+            r#"(document [0, 0] - [2, 0] 0..14
+  (section [0, 0] - [0, 6] 0..6
+    ("[" [0, 0] - [0, 1] 0..1)
+    name: (name [0, 1] - [0, 5] 1..5)
+    ("]" [0, 5] - [0, 6] 5..6))
+  (item [1, 0] - [1, 6] 7..13
+    key: (key [1, 0] - [1, 4] 7..11)
+    ("=" [1, 4] - [1, 5] 11..12)
+    value: (value [1, 5] - [1, 6] 12..13
+      (number [1, 5] - [1, 6] 12..13))))"#
+        );
+    }
+
+    #[test]
+    fn marks_missing_nodes_in_sexp() {
+        let text = format!("]{}", "x".repeat(40));
+        assert!(sexp(&text).ends_with("(MISSING = [0, 41] - [0, 41] 41..41)))"));
+    }
+
+    #[test]
+    fn dumps_json_with_text_of_leaves() {
+        let value = json("[a]");
+        assert_eq!(value["kind"], "document");
+        assert!(value.get("text").is_none());
+
+        let name = &value["children"][0]["children"][1];
+        assert_eq!(
+            *name,
+            json!({
+                "kind": "name",
+                "named": true,
+                "field": "name",
+                "startByte": 1,
+                "endByte": 2,
+                "startPoint": { "row": 0, "column": 1 },
+                "endPoint": { "row": 0, "column": 2 },
+                "text": "a",
+            })
+        );
+    }
+
+    #[test]
+    fn flags_error_and_missing_nodes_in_json() {
+        let value = json("[a]]");
+        assert_eq!(value["children"][1]["error"], true);
+
+        let value = json(&format!("]{}", "x".repeat(40)));
+        let missing = &value["children"][0]["children"][1];
+        assert_eq!(missing["kind"], "=");
+        assert_eq!(missing["missing"], true);
+    }
+}
@@ -0,0 +1,23 @@
+//! Analysis core of the Warcraft III language server.
+//!
+//! - [`lng`]: language backends, parsed documents, diagnostics, symbols and the workspace index.
+//! - [`lsp`]: protocol types and framing, independent of the rest of the crate.
+//! - [`util`]: line tables, encodings and the state shared by the server.
+//! - `server`: the stdio language server the binary runs, feature `server`.
+//! - `wasm`: JavaScript API for `wasm32-unknown-unknown`, feature `wasm`.
+//!
+//! ```no_run
+//! let backend = jass::lng::backend("bni").unwrap();
+//! let document = jass::lng::Document::parse(backend, "[Section]\nKey=Value\n");
+//! let uri = url::Url::parse("file:///war3mapMisc.bni").unwrap();
+//! let diagnostics = document.diagnostics(&uri);
+//! let symbols = document.symbols();
+//! ```
+
+pub mod lng;
+pub mod lsp;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,300 @@
+use crate::lng::bni::diagnostic::{DUPLICATE_KEY, DUPLICATE_SECTION, ORPHAN_KEY};
+use crate::lng::bni::model::{self, Bni, Section};
+use crate::lng::format::{self, LineEdit};
+use crate::lsp::code_action::{CodeAction, CodeActionKind};
+use crate::lsp::diagnostic::Diagnostic;
+use crate::lsp::range::Range;
+use crate::lsp::workspace::WorkspaceEdit;
+use crate::util::line_list::LineList;
+use std::collections::BTreeMap;
+use std::iter;
+use std::mem;
+use tree_sitter::Tree;
+use url::Url;
+
+/// Removing duplicate keys, merging duplicate sections, putting keys above the first
+/// header into a section of their own and sorting keys, for the lines `range` touches.
+/// Problems are found in the document, `diagnostics` of the client only get attached to
+/// the fixes of them.
+pub fn actions(
+    tree: &Tree,
+    line_list: &LineList,
+    uri: &Url,
+    range: Range,
+    diagnostics: &[Diagnostic],
+) -> Vec<CodeAction> {
+    let bni = model::read(tree, line_list);
+    let touches = |r: &Range| r.start.line <= range.end.line && range.start.line <= r.end.line;
+    let action = |title: String,
+                  kind: CodeActionKind,
+                  fixes: Option<(&str, Vec<Range>)>,
+                  edits: Vec<LineEdit>| {
+        let fixed = |d: &&Diagnostic| {
+            fixes.as_ref().is_some_and(|(code, ranges)| {
+                d.code.as_deref() == Some(*code) && ranges.contains(&d.range)
+            })
+        };
+        CodeAction {
+            title,
+            kind,
+            diagnostics: diagnostics.iter().filter(fixed).cloned().collect(),
+            is_preferred: fixes.is_some(),
+            edit: WorkspaceEdit {
+                changes: BTreeMap::from([(uri.clone(), format::text_edits(line_list, &edits))]),
+            },
+        }
+    };
+    let mut result = Vec::new();
+
+    let groups = iter::once(&bni.orphans).chain(bni.sections.iter().map(|s| &s.items));
+    for items in groups {
+        let firsts = model::first_of_name(items.iter().map(|i| i.key.as_str()));
+        for (i, item) in items.iter().enumerate() {
+            if firsts[i] == i || item.key.is_empty() || !touches(&item.range) {
+                continue;
+            }
+            let row = item.range.start.line;
+            result.push(action(
+                format!("Remove duplicate key `{}`", item.key),
+                CodeActionKind::QuickFix,
+                Some((DUPLICATE_KEY, vec![item.key_range])),
+                vec![LineEdit {
+                    lines: row..row + 1,
+                    text: Vec::new(),
+                }],
+            ));
+        }
+    }
+
+    let firsts = model::first_of_name(bni.sections.iter().map(|s| s.name()));
+    for (i, section) in bni.sections.iter().enumerate() {
+        let first = firsts[i];
+        if first == i || !touches(&section.header.range) {
+            continue;
+        }
+        result.push(action(
+            format!("Merge into the first `[{}]`", section.name()),
+            CodeActionKind::QuickFix,
+            Some((DUPLICATE_SECTION, vec![section.header.name_range])),
+            vec![merge(&bni, first, i, line_list)],
+        ));
+    }
+
+    if bni.orphans.iter().any(|item| touches(&item.range)) {
+        let name = unused_name(&bni);
+        result.push(action(
+            format!("Put keys above the first section into new `[{name}]`"),
+            CodeActionKind::QuickFix,
+            Some((
+                ORPHAN_KEY,
+                bni.orphans.iter().map(|i| i.key_range).collect(),
+            )),
+            vec![enclose(&bni, &name, line_list)],
+        ));
+    }
+
+    for section in bni.sections.iter().filter(|s| touches(&s.range())) {
+        if let Some(edit) = sort(section, line_list) {
+            result.push(action(
+                format!("Sort keys of `[{}]`", section.name()),
+                CodeActionKind::RefactorRewrite,
+                None,
+                vec![edit],
+            ));
+        }
+    }
+
+    result
+}
+
+/// Moves what is under header `index` to the end of section `into` and drops the header.
+fn merge(bni: &Bni, into: usize, index: usize, line_list: &LineList) -> LineEdit {
+    let lines = &line_list.lines;
+    let after = bni.extent(into, line_list).end.line + 1;
+    let header = bni.sections[index].header.range.start.line;
+    let end = bni.extent(index, line_list).end.line + 1;
+
+    // Sections in between keep their blank lines, except the ones the header had above.
+    let mut between = lines[after..header].to_vec();
+    while between.last().is_some_and(|l| l.trim().is_empty()) {
+        between.pop();
+    }
+    let mut text = lines[header + 1..end].to_vec();
+    text.extend(between);
+    LineEdit {
+        lines: after..end,
+        text,
+    }
+}
+
+/// Header above the first key outside of sections, and the comments right above it.
+fn enclose(bni: &Bni, name: &str, line_list: &LineList) -> LineEdit {
+    let mut row = bni.orphans[0].range.start.line;
+    while row > 0 && model::is_comment(&line_list.lines[row - 1]) {
+        row -= 1;
+    }
+    LineEdit {
+        lines: row..row,
+        text: vec![format!("[{name}]")],
+    }
+}
+
+/// `Section`, or `Section2` and so on if the document has it.
+fn unused_name(bni: &Bni) -> String {
+    (1..)
+        .map(|n| match n {
+            1 => "Section".to_string(),
+            n => format!("Section{n}"),
+        })
+        .find(|name| {
+            !bni.sections
+                .iter()
+                .any(|s| model::same_name(s.name(), name))
+        })
+        .unwrap()
+}
+
+/// Keys ordered by name the way the formatter sorts them: comments above a key move with
+/// it and blank lines between keys go. `None` when they are in order already.
+fn sort(section: &Section, line_list: &LineList) -> Option<LineEdit> {
+    let items = &section.items;
+    if items
+        .windows(2)
+        .all(|w| w[0].key.to_lowercase() <= w[1].key.to_lowercase())
+    {
+        return None;
+    }
+
+    let start = section.header.range.start.line + 1;
+    let end = items.last()?.range.start.line + 1;
+    let mut keys = items.iter().peekable();
+    let mut entries: Vec<(String, Vec<String>)> = Vec::new();
+    let mut pending = Vec::new();
+    for row in start..end {
+        let line = &line_list.lines[row];
+        if line.trim().is_empty() {
+            continue;
+        }
+        pending.push(line.clone());
+        if let Some(item) = keys.next_if(|i| i.range.start.line == row) {
+            entries.push((item.key.to_lowercase(), mem::take(&mut pending)));
+        }
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Some(LineEdit {
+        lines: start..end,
+        text: entries.into_iter().flat_map(|(_, lines)| lines).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::diagnostic::diagnostics;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+    use crate::lsp::position::Position;
+    use crate::lsp::text_document::TextEdit;
+
+    /// Titles of the actions for lines `first..=last`, with the text after each.
+    fn fixes(text: &str, first: usize, last: usize) -> Vec<(String, String)> {
+        let document = Document::parse(&BACKEND, text);
+        let uri = Url::parse("file:///test/units.bni").unwrap();
+        let range = Range {
+            start: Position {
+                line: first,
+                character: 0,
+            },
+            end: Position {
+                line: last,
+                character: 0,
+            },
+        };
+        actions(&document.tree, &document.line_list, &uri, range, &[])
+            .into_iter()
+            .map(|action| {
+                let edits = &action.edit.changes[&uri];
+                (action.title, applied(&document.line_list, edits))
+            })
+            .collect()
+    }
+
+    /// Text after the edits, applied from the last one.
+    fn applied(line_list: &LineList, edits: &[TextEdit]) -> String {
+        let offset = |p: Position| -> usize {
+            line_list.lines[..p.line]
+                .iter()
+                .map(|l| l.len() + 1)
+                .sum::<usize>()
+                + p.character
+        };
+        let mut text = line_list.lines.join("\n");
+        for edit in edits.iter().rev() {
+            let range = offset(edit.range.start)..offset(edit.range.end);
+            text.replace_range(range, &edit.new_text);
+        }
+        text
+    }
+
+    #[test]
+    fn removes_duplicate_key() {
+        assert_eq!(
+            fixes("[hfoo]\nHp=1\nName=A\nname=B\n", 3, 3),
+            [(
+                "Remove duplicate key `name`".to_string(),
+                "[hfoo]\nHp=1\nName=A".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn merges_duplicate_section_into_first() {
+        let text = "[a]\nx=1\n\n[b]\ny=2\n\n[A]\nz=3\n";
+        assert_eq!(
+            fixes(text, 6, 6),
+            [(
+                "Merge into the first `[A]`".to_string(),
+                "[a]\nx=1\nz=3\n\n[b]\ny=2".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn encloses_orphans_with_their_comments() {
+        let text = "; top\nx=1\n[Section]\ny=2\n";
+        assert_eq!(
+            fixes(text, 1, 1),
+            [(
+                "Put keys above the first section into new `[Section2]`".to_string(),
+                "[Section2]\n; top\nx=1\n[Section]\ny=2".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn sorts_keys_with_comments() {
+        let text = "[a]\nb=2\n; first\nA=1\n";
+        assert_eq!(
+            fixes(text, 0, 0),
+            [(
+                "Sort keys of `[a]`".to_string(),
+                "[a]\n; first\nA=1\nb=2".to_string()
+            )]
+        );
+        assert!(fixes("[a]\nA=1\nb=2\n", 0, 2).is_empty());
+    }
+
+    #[test]
+    fn attaches_diagnostics_it_fixes() {
+        let text = "[hfoo]\nName=A\nname=B\n";
+        let document = Document::parse(&BACKEND, text);
+        let uri = Url::parse("file:///test/units.bni").unwrap();
+        let found = diagnostics(&document.tree, &document.line_list, &uri);
+        let range = found[0].range;
+        let fixes = actions(&document.tree, &document.line_list, &uri, range, &found);
+        assert_eq!(fixes.len(), 1);
+        assert!(fixes[0].is_preferred);
+        assert_eq!(fixes[0].diagnostics[0].code.as_deref(), Some(DUPLICATE_KEY));
+    }
+}
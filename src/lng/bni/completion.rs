@@ -0,0 +1,154 @@
+use crate::lng::bni::model;
+use crate::lng::bni::schema::{self, Schema, ValueType};
+use crate::lsp::completion::{CompletionItem, CompletionItemKind};
+use crate::lsp::hover::MarkupContent;
+use crate::lsp::position::Position;
+use crate::util::line_list::LineList;
+use tree_sitter::Tree;
+use url::Url;
+
+/// From the schema: section names after `[`, keys missing from the section at the start
+/// of a line, enum values and booleans after `=`.
+pub fn completion(
+    tree: &Tree,
+    line_list: &LineList,
+    uri: &Url,
+    position: Position,
+) -> Vec<CompletionItem> {
+    match schema::for_uri(uri) {
+        Some(schema) => complete(tree, line_list, &schema, position),
+        None => Vec::new(),
+    }
+}
+
+fn complete(
+    tree: &Tree,
+    line_list: &LineList,
+    schema: &Schema,
+    position: Position,
+) -> Vec<CompletionItem> {
+    let line = line_list
+        .lines
+        .get(position.line)
+        .map_or("", String::as_str);
+    let before = line.get(..position.character).unwrap_or(line);
+
+    if before.trim_start().starts_with('[') {
+        let close = if line.contains(']') { "" } else { "]" };
+        return schema
+            .sections
+            .iter()
+            .filter(|(name, _)| name.as_str() != "*")
+            .map(|(name, section)| CompletionItem {
+                label: name.clone(),
+                kind: Some(CompletionItemKind::Module),
+                detail: None,
+                documentation: section.doc.as_ref().map(MarkupContent::markdown),
+                insert_text: Some(format!("{name}{close}")),
+            })
+            .collect();
+    }
+
+    let bni = model::read(tree, line_list);
+    let Some(section) = bni
+        .sections
+        .iter()
+        .rfind(|s| s.header.range.start.line < position.line)
+    else {
+        return Vec::new();
+    };
+    let Some(section_schema) = schema.section(section.name()) else {
+        return Vec::new();
+    };
+
+    if let Some((key, _)) = before.split_once('=') {
+        let key = key.trim();
+        let Some((_, key)) = section_schema
+            .keys
+            .iter()
+            .find(|(name, _)| model::same_name(name, key))
+        else {
+            return Vec::new();
+        };
+        let values = match key.value_type {
+            ValueType::Enum => key.values.clone(),
+            ValueType::Boolean => vec!["true".into(), "false".into()],
+            _ => Vec::new(),
+        };
+        return values
+            .into_iter()
+            .map(|value| CompletionItem {
+                label: value,
+                kind: Some(CompletionItemKind::EnumMember),
+                detail: None,
+                documentation: None,
+                insert_text: None,
+            })
+            .collect();
+    }
+
+    let present = |name: &str| {
+        section
+            .items
+            .iter()
+            .any(|i| i.range.start.line != position.line && model::same_name(&i.key, name))
+    };
+    section_schema
+        .keys
+        .iter()
+        .filter(|(name, _)| !present(name))
+        .map(|(name, key)| CompletionItem {
+            label: name.clone(),
+            kind: Some(CompletionItemKind::Property),
+            detail: Some(key.type_name()),
+            documentation: Some(MarkupContent::markdown(key.describe(name))),
+            insert_text: Some(format!("{name}=")),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::schema::tests::skin;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    fn labels(text: &str, line: usize, character: usize) -> Vec<String> {
+        let document = Document::parse(&BACKEND, text);
+        let position = Position { line, character };
+        complete(&document.tree, &document.line_list, &skin(), position)
+            .into_iter()
+            .map(|c| c.label)
+            .collect()
+    }
+
+    #[test]
+    fn completes_section_names() {
+        assert_eq!(labels("[", 0, 1), ["Units"]);
+    }
+
+    #[test]
+    fn completes_missing_keys() {
+        let text = "[Units]\nname=Footman\n\n";
+        assert_eq!(labels(text, 2, 0), ["Hidden", "Kind"]);
+    }
+
+    #[test]
+    fn completes_enum_and_boolean_values() {
+        let text = "[Units]\nKind=\nHidden=\n";
+        assert_eq!(labels(text, 1, 5), ["melee", "ranged"]);
+        assert_eq!(labels(text, 2, 7), ["true", "false"]);
+    }
+
+    #[test]
+    fn skips_files_without_schema() {
+        let document = Document::parse(&BACKEND, "[");
+        let uri = Url::parse("file:///map/units.bni").unwrap();
+        let position = Position {
+            line: 0,
+            character: 1,
+        };
+        assert!(completion(&document.tree, &document.line_list, &uri, position).is_empty());
+    }
+}
@@ -0,0 +1,524 @@
+use crate::lng::bni::error::{Error, Result};
+use crate::lng::bni::model::{self, Bni, Item, Section};
+use crate::lng::Document;
+use crate::lsp::position::Position;
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Unexpected,
+    Visitor,
+};
+use serde::forward_to_deserialize_any;
+use std::collections::{hash_map, HashMap};
+
+/// Reads `T` from BNI text.
+///
+/// The document is a map of section names to sections, each a map of keys to values.
+/// Keys above the first header are entries of the document itself. Sections of the same
+/// name read as one, keys in the order they come. Values are scalars, or sequences when
+/// split by commas outside double quotes. An empty value reads as `None`.
+pub fn from_str<T: DeserializeOwned>(text: &str) -> Result<T> {
+    let deserializer = Deserializer::new(text)?;
+    let start = Position {
+        line: 0,
+        character: 0,
+    };
+    T::deserialize(&deserializer).map_err(|e| e.at(start))
+}
+
+/// Parsed BNI document, what `from_str` deserializes from.
+pub struct Deserializer {
+    bni: Bni,
+}
+
+impl Deserializer {
+    /// Fails at the first line that is not a header, a key or a comment.
+    pub fn new(text: &str) -> Result<Self> {
+        let document = Document::parse(&super::BACKEND, text);
+        let bni = model::read(&document.tree, &document.line_list);
+
+        let mut problems: Vec<(Position, &str)> = bni
+            .errors
+            .iter()
+            .map(|range| {
+                (
+                    range.start,
+                    "expected `[section]`, `key=value` or a comment",
+                )
+            })
+            .collect();
+        let items = bni
+            .orphans
+            .iter()
+            .chain(bni.sections.iter().flat_map(|s| &s.items));
+        problems.extend(
+            items
+                .filter(|item| item.key.is_empty())
+                .map(|item| (item.range.start, "empty key")),
+        );
+        problems.extend(
+            bni.sections
+                .iter()
+                .filter(|s| !s.header.closed)
+                .map(|s| (s.header.range.end, "expected `]`")),
+        );
+
+        match problems
+            .into_iter()
+            .min_by_key(|(p, _)| (p.line, p.character))
+        {
+            Some((position, message)) => Err(Error::new(message, position)),
+            None => Ok(Self { bni }),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &Deserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let mut entries: Vec<Entry> = self.bni.orphans.iter().map(Entry::Key).collect();
+        // Index in `entries` of the first section of each name.
+        let mut first: HashMap<String, usize> = HashMap::new();
+        for section in &self.bni.sections {
+            match first.entry(section.name().to_ascii_lowercase()) {
+                hash_map::Entry::Occupied(at) => {
+                    if let Entry::Section(same) = &mut entries[*at.get()] {
+                        same.push(section);
+                    }
+                }
+                hash_map::Entry::Vacant(at) => {
+                    at.insert(entries.len());
+                    entries.push(Entry::Section(vec![section]));
+                }
+            }
+        }
+        visitor.visit_map(Entries { entries, index: 0 })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Key of the document or of a section, or every section of one name.
+enum Entry<'a> {
+    Key(&'a Item),
+    Section(Vec<&'a Section>),
+}
+
+/// Keys and sections, as map entries.
+struct Entries<'a> {
+    entries: Vec<Entry<'a>>,
+    /// Of the next value, one past the key.
+    index: usize,
+}
+
+impl<'de> MapAccess<'de> for Entries<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some(entry) = self.entries.get(self.index) else {
+            return Ok(None);
+        };
+        self.index += 1;
+        let (name, position) = match entry {
+            Entry::Key(item) => (item.key.as_str(), item.key_range.start),
+            Entry::Section(sections) => (sections[0].name(), sections[0].header.name_range.start),
+        };
+        let key: StrDeserializer<Error> = name.into_deserializer();
+        seed.deserialize(key).map(Some).map_err(|e| e.at(position))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        match &self.entries[self.index - 1] {
+            Entry::Key(item) => seed
+                .deserialize(ValueDeserializer::of(item))
+                .map_err(|e| e.at(item.value_range.start)),
+            Entry::Section(sections) => seed
+                .deserialize(SectionDeserializer(sections))
+                .map_err(|e| e.at(sections[0].header.range.start)),
+        }
+    }
+}
+
+/// Keys of the sections of one name.
+struct SectionDeserializer<'a, 'b>(&'b [&'a Section]);
+
+impl<'de> de::Deserializer<'de> for SectionDeserializer<'_, '_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(Entries {
+            entries: self
+                .0
+                .iter()
+                .flat_map(|s| &s.items)
+                .map(Entry::Key)
+                .collect(),
+            index: 0,
+        })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Value of a key, or one element of a comma list.
+struct ValueDeserializer<'a> {
+    text: &'a str,
+    position: Position,
+    /// Already split off a list, so no list itself.
+    element: bool,
+}
+
+impl<'a> ValueDeserializer<'a> {
+    fn of(item: &'a Item) -> Self {
+        Self {
+            text: &item.value,
+            position: item.value_range.start,
+            element: false,
+        }
+    }
+
+    /// Elements without the commas and the whitespace around them.
+    fn elements(&self) -> Vec<ValueDeserializer<'a>> {
+        if self.text.is_empty() {
+            return Vec::new();
+        }
+        model::split(self.text)
+            .into_iter()
+            .filter(|(_, part)| *part != ",")
+            .map(|(offset, part)| {
+                let trimmed = part.trim_start();
+                Self {
+                    text: trimmed.trim_end(),
+                    position: Position {
+                        line: self.position.line,
+                        character: self.position.character + offset + part.len() - trimmed.len(),
+                    },
+                    element: true,
+                }
+            })
+            .collect()
+    }
+
+    /// Text without the double quotes around it.
+    fn unquoted(&self) -> &'a str {
+        unquote(self.text).1
+    }
+}
+
+/// `"text"` -> `text`, anything else as is, with the byte offset of the result in `text`.
+pub(crate) fn unquote(text: &str) -> (usize, &str) {
+    match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        Some(inner) => (1, inner),
+        None => (0, text),
+    }
+}
+
+macro_rules! parse_value {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+                match self.unquoted().parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(_) => Err(de::Error::invalid_value(Unexpected::Str(self.text), &visitor)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = Error;
+
+    /// Lists, then quoted strings, booleans, integers and numbers, other text as string.
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let text = self.text;
+        if !self.element && model::split(text).len() > 1 {
+            return self.deserialize_seq(visitor);
+        }
+        if text.starts_with('"') {
+            return visitor.visit_str(self.unquoted());
+        }
+        if text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false") {
+            return visitor.visit_bool(text.eq_ignore_ascii_case("true"));
+        }
+        if let Ok(value) = text.parse::<i64>() {
+            return visitor.visit_i64(value);
+        }
+        if let Ok(value) = text.parse::<u64>() {
+            return visitor.visit_u64(value);
+        }
+        let numeric =
+            text.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'));
+        match text.parse::<f64>() {
+            Ok(value) if numeric => visitor.visit_f64(value),
+            _ => visitor.visit_str(text),
+        }
+    }
+
+    /// `true`, `false`, `1` or `0`.
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.unquoted().to_ascii_lowercase().as_str() {
+            "true" | "1" => visitor.visit_bool(true),
+            "false" | "0" => visitor.visit_bool(false),
+            _ => Err(de::Error::invalid_value(
+                Unexpected::Str(self.text),
+                &visitor,
+            )),
+        }
+    }
+
+    parse_value! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_str(self.unquoted())
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bytes(self.unquoted().as_bytes())
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.text.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.text.is_empty() {
+            true => visitor.visit_unit(),
+            false => Err(de::Error::invalid_type(
+                Unexpected::Str(self.text),
+                &visitor,
+            )),
+        }
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.element {
+            return Err(de::Error::custom("lists don't nest in BNI"));
+        }
+        visitor.visit_seq(Elements(self.elements().into_iter()))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        Err(de::Error::invalid_type(
+            Unexpected::Str(self.text),
+            &visitor,
+        ))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_map(visitor)
+    }
+
+    /// Unit variants by name.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let variant: StrDeserializer<Error> = self.unquoted().into_deserializer();
+        visitor.visit_enum(variant)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+}
+
+/// Elements of a comma list.
+struct Elements<'a>(std::vec::IntoIter<ValueDeserializer<'a>>);
+
+impl<'de> SeqAccess<'de> for Elements<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        let Some(element) = self.0.next() else {
+            return Ok(None);
+        };
+        let position = element.position;
+        seed.deserialize(element)
+            .map(Some)
+            .map_err(|e| e.at(position))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Unit {
+        #[serde(rename = "Name")]
+        name: String,
+        #[serde(rename = "Buttonpos")]
+        buttonpos: Vec<u32>,
+        #[serde(rename = "Tip")]
+        tip: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct File {
+        version: u32,
+        hfoo: Unit,
+    }
+
+    #[test]
+    fn reads_sections_and_comma_lists() {
+        let text = "// units\nversion=2\n\n[hfoo]\nName=\"Footman, Elite\"\nButtonpos=0, 2\nTip=\n";
+        let file: File = from_str(text).unwrap();
+        assert_eq!(
+            file,
+            File {
+                version: 2,
+                hfoo: Unit {
+                    name: "Footman, Elite".to_string(),
+                    buttonpos: vec![0, 2],
+                    tip: None,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let err = from_str::<File>("version=2\n[hfoo]\nName=x\nButtonpos=1,a\n").unwrap_err();
+        let position = err.position().unwrap();
+        assert_eq!((position.line, position.character), (3, 12));
+        assert!(err.to_string().ends_with("at line 4 column 13"), "{err}");
+    }
+
+    #[test]
+    fn merges_sections_of_same_name() {
+        let map: BTreeMap<String, BTreeMap<String, u32>> =
+            from_str("[a]\nx=1\n[b]\n[A]\ny=2\n").unwrap();
+        assert_eq!(
+            map,
+            BTreeMap::from([
+                (
+                    "a".into(),
+                    BTreeMap::from([("x".into(), 1), ("y".into(), 2)])
+                ),
+                ("b".into(), BTreeMap::new()),
+            ])
+        );
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Keys {
+            x: u32,
+            y: u32,
+        }
+        let file: BTreeMap<String, Keys> = from_str("[a]\nx=1\n[a]\ny=2\n").unwrap();
+        assert_eq!(file["a"], Keys { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn rejects_unterminated_header() {
+        let err = from_str::<File>("version=2\n[hfoo\nName=x\n").unwrap_err();
+        assert_eq!(err.to_string(), "expected `]` at line 2 column 6");
+    }
+
+    #[test]
+    fn rejects_lines_that_are_no_entries() {
+        let err = from_str::<File>("version=2\njunk line\n").unwrap_err();
+        assert_eq!(err.position().map(|p| p.line), Some(1));
+    }
+}
@@ -0,0 +1,310 @@
+use crate::lng::bni::model::{self, Bni, Item};
+use crate::lng::bni::schema::{self, Schema};
+use crate::lsp::diagnostic::{Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity};
+use crate::lsp::range::Range;
+use crate::lsp::symbol::Location;
+use crate::util::line_list::LineList;
+use tree_sitter::Tree;
+use url::Url;
+
+/// `code` of each check, code actions match on these.
+pub const DUPLICATE_SECTION: &str = "duplicate-section";
+pub const DUPLICATE_KEY: &str = "duplicate-key";
+pub const ORPHAN_KEY: &str = "orphan-key";
+pub const UNTERMINATED_SECTION: &str = "unterminated-section";
+pub const EMPTY_KEY: &str = "empty-key";
+pub const UNPARSABLE_LINE: &str = "unparsable-line";
+pub const UNKNOWN_SECTION: &str = "unknown-section";
+pub const UNKNOWN_KEY: &str = "unknown-key";
+pub const WRONG_TYPE: &str = "wrong-type";
+
+/// Structure checks, in place of the generic syntax report: every line the parser
+/// gave up on is an unparsable line here. Files a schema applies to get checked against it.
+pub fn diagnostics(tree: &Tree, line_list: &LineList, uri: &Url) -> Vec<Diagnostic> {
+    report(tree, line_list, uri, schema::for_uri(uri).as_deref())
+}
+
+fn report(
+    tree: &Tree,
+    line_list: &LineList,
+    uri: &Url,
+    schema: Option<&Schema>,
+) -> Vec<Diagnostic> {
+    let bni = model::read(tree, line_list);
+    let mut result = Vec::new();
+    let first_here = |range: Range, message: String| {
+        Some(vec![DiagnosticRelatedInformation {
+            location: Location {
+                uri: uri.clone(),
+                range,
+            },
+            message,
+        }])
+    };
+
+    for item in &bni.orphans {
+        result.push(diagnostic(
+            item.key_range,
+            DiagnosticSeverity::Warning,
+            ORPHAN_KEY,
+            format!("key `{}` is outside of any section", item.key),
+        ));
+    }
+    keys(&bni.orphans, None, &mut result, &first_here);
+
+    let firsts = model::first_of_name(bni.sections.iter().map(|s| s.name()));
+    for (i, section) in bni.sections.iter().enumerate() {
+        let header = &section.header;
+        if !header.closed {
+            result.push(diagnostic(
+                header.range,
+                DiagnosticSeverity::Error,
+                UNTERMINATED_SECTION,
+                format!("section `[{}` is missing `]`", header.name),
+            ));
+        }
+
+        if firsts[i] != i {
+            let first = &bni.sections[firsts[i]];
+            result.push(Diagnostic {
+                related_information: first_here(
+                    first.header.name_range,
+                    format!("first `[{}]` is here", first.name()),
+                ),
+                ..diagnostic(
+                    header.name_range,
+                    DiagnosticSeverity::Warning,
+                    DUPLICATE_SECTION,
+                    format!("duplicate section `[{}]`", section.name()),
+                )
+            });
+        }
+
+        keys(
+            &section.items,
+            Some(section.name()),
+            &mut result,
+            &first_here,
+        );
+    }
+
+    for range in &bni.errors {
+        result.push(diagnostic(
+            *range,
+            DiagnosticSeverity::Error,
+            UNPARSABLE_LINE,
+            "unparsable line, expected `[Section]`, `key=value` or a comment".into(),
+        ));
+    }
+
+    if let Some(schema) = schema {
+        checks(schema, &bni, &mut result);
+    }
+
+    result.sort_by_key(|d| (d.range.start.line, d.range.start.character));
+    result
+}
+
+/// Unknown sections and keys, and values not of the declared type.
+fn checks(schema: &Schema, bni: &Bni, result: &mut Vec<Diagnostic>) {
+    for section in &bni.sections {
+        let Some(section_schema) = schema.section(section.name()) else {
+            result.push(diagnostic(
+                section.header.name_range,
+                DiagnosticSeverity::Warning,
+                UNKNOWN_SECTION,
+                format!("unknown section `[{}]`", section.name()),
+            ));
+            continue;
+        };
+
+        for item in section.items.iter().filter(|i| !i.key.is_empty()) {
+            let key = section_schema
+                .keys
+                .iter()
+                .find(|(name, _)| model::same_name(name, &item.key));
+            let Some((name, key)) = key else {
+                result.push(diagnostic(
+                    item.key_range,
+                    DiagnosticSeverity::Warning,
+                    UNKNOWN_KEY,
+                    format!("unknown key `{}` in `[{}]`", item.key, section.name()),
+                ));
+                continue;
+            };
+
+            let start = item.value_range.start;
+            let values = match key.list {
+                true => model::split(&item.value),
+                false => vec![(0, item.value.as_str())],
+            };
+            for (offset, value) in values {
+                let trimmed = value.trim();
+                if value == "," || trimmed.is_empty() || key.accepts(trimmed) {
+                    continue;
+                }
+                let offset = offset + value.len() - value.trim_start().len();
+                let mut range = Range { start, end: start };
+                range.start.character += offset;
+                range.end.character = range.start.character + trimmed.len();
+                result.push(diagnostic(
+                    range,
+                    DiagnosticSeverity::Warning,
+                    WRONG_TYPE,
+                    format!("`{name}` expects {}, got `{trimmed}`", key.type_name()),
+                ));
+            }
+        }
+    }
+}
+
+/// Empty and repeated keys of one section.
+fn keys(
+    items: &[Item],
+    section: Option<&str>,
+    result: &mut Vec<Diagnostic>,
+    first_here: &impl Fn(Range, String) -> Option<Vec<DiagnosticRelatedInformation>>,
+) {
+    let firsts = model::first_of_name(items.iter().map(|i| i.key.as_str()));
+    for (i, item) in items.iter().enumerate() {
+        if item.key.is_empty() {
+            let mut range = Range {
+                start: item.separator,
+                end: item.separator,
+            };
+            range.end.character += 1;
+            result.push(diagnostic(
+                range,
+                DiagnosticSeverity::Error,
+                EMPTY_KEY,
+                "empty key before `=`".into(),
+            ));
+            continue;
+        }
+
+        if firsts[i] != i {
+            let first = &items[firsts[i]];
+            let message = match section {
+                Some(section) => format!("duplicate key `{}` in `[{section}]`", item.key),
+                None => format!("duplicate key `{}`", item.key),
+            };
+            result.push(Diagnostic {
+                related_information: first_here(
+                    first.key_range,
+                    format!("first `{}` is here", first.key),
+                ),
+                ..diagnostic(
+                    item.key_range,
+                    DiagnosticSeverity::Warning,
+                    DUPLICATE_KEY,
+                    message,
+                )
+            });
+        }
+    }
+}
+
+fn diagnostic(
+    range: Range,
+    severity: DiagnosticSeverity,
+    code: &str,
+    message: String,
+) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        code: Some(code.to_string()),
+        source: Some(super::BACKEND.id.to_string()),
+        message,
+        related_information: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    fn check(text: &str) -> Vec<Diagnostic> {
+        let document = Document::parse(&BACKEND, text);
+        let uri = Url::parse("file:///test/units.bni").unwrap();
+        diagnostics(&document.tree, &document.line_list, &uri)
+    }
+
+    fn codes(text: &str) -> Vec<(usize, String)> {
+        check(text)
+            .into_iter()
+            .map(|d| (d.range.start.line, d.code.unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn points_duplicate_key_at_first_one() {
+        let found = check("[hfoo]\nName=Footman\nname=Knight\n");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].code.as_deref(), Some(DUPLICATE_KEY));
+        assert_eq!(found[0].message, "duplicate key `name` in `[hfoo]`");
+        let related = found[0].related_information.as_ref().unwrap();
+        assert_eq!(related[0].location.range.start.line, 1);
+        assert_eq!(related[0].message, "first `Name` is here");
+    }
+
+    #[test]
+    fn points_duplicate_section_at_first_one() {
+        let found = check("[hfoo]\nA=1\n[hpea]\n[HFOO]\nB=2\n");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].code.as_deref(), Some(DUPLICATE_SECTION));
+        assert_eq!(found[0].range.start.line, 3);
+        let related = found[0].related_information.as_ref().unwrap();
+        assert_eq!(related[0].location.range.start.line, 0);
+    }
+
+    #[test]
+    fn reports_unterminated_brackets() {
+        let found = check("[hfoo\nName=Footman\n");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].code.as_deref(), Some(UNTERMINATED_SECTION));
+        assert_eq!(found[0].message, "section `[hfoo` is missing `]`");
+    }
+
+    #[test]
+    fn reports_structure_problems_in_line_order() {
+        let text = "loose=1\n// note\n[hfoo]\n=1\njunk line\nName=x\n";
+        assert_eq!(
+            codes(text),
+            [
+                (0, ORPHAN_KEY.to_string()),
+                (3, EMPTY_KEY.to_string()),
+                (4, UNPARSABLE_LINE.to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn accepts_clean_file() {
+        assert!(check("; units\n[hfoo]\nName=Footman\n\n[hpea]\nName=Peasant\n").is_empty());
+    }
+
+    #[test]
+    fn checks_against_schema() {
+        let text = "[Units]\nName=Footman\nArmor=2\nKind=melee, flying\nHidden=yes\n[Items]\n";
+        let document = Document::parse(&BACKEND, text);
+        let uri = Url::parse("file:///map/war3mapSkin.bni").unwrap();
+        let schema = crate::lng::bni::schema::tests::skin();
+        let found: Vec<(usize, usize, String)> =
+            report(&document.tree, &document.line_list, &uri, Some(&schema))
+                .into_iter()
+                .map(|d| (d.range.start.line, d.range.start.character, d.code.unwrap()))
+                .collect();
+        assert_eq!(
+            found,
+            [
+                (2, 0, UNKNOWN_KEY.to_string()),
+                (3, 12, WRONG_TYPE.to_string()),
+                (4, 7, WRONG_TYPE.to_string()),
+                (5, 1, UNKNOWN_SECTION.to_string()),
+            ]
+        );
+    }
+}
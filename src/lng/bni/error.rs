@@ -0,0 +1,72 @@
+use crate::lsp::position::Position;
+use std::fmt::{self, Display};
+
+/// Error of reading or writing BNI through serde.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    message: String,
+    /// Where in the text, unknown for errors of writing.
+    position: Option<Position>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub(crate) fn new(message: impl Display, position: Position) -> Self {
+        Self {
+            message: message.to_string(),
+            position: Some(position),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Zero based line and byte column.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// Puts the error at `position` unless a nested value already did.
+    pub(crate) fn at(mut self, position: Position) -> Self {
+        self.position.get_or_insert(position);
+        self
+    }
+}
+
+/// `message at line 3 column 7`, one based.
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some(p) => write!(
+                f,
+                "{} at line {} column {}",
+                self.message,
+                p.line + 1,
+                p.character + 1
+            ),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Self {
+            message: message.to_string(),
+            position: None,
+        }
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(message: T) -> Self {
+        Self {
+            message: message.to_string(),
+            position: None,
+        }
+    }
+}
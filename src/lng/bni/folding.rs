@@ -0,0 +1,64 @@
+use crate::lng::bni::model;
+use crate::lsp::folding::{FoldingRange, FoldingRangeKind};
+use crate::util::line_list::LineList;
+use tree_sitter::Tree;
+
+/// Each section from its header to the line before the next one, and runs of comment lines.
+pub fn folding(tree: &Tree, line_list: &LineList) -> Vec<FoldingRange> {
+    let bni = model::read(tree, line_list);
+    let mut result = Vec::new();
+
+    for index in 0..bni.sections.len() {
+        let extent = bni.extent(index, line_list);
+        if extent.end.line > extent.start.line {
+            result.push(FoldingRange {
+                start_line: extent.start.line,
+                end_line: extent.end.line,
+                kind: None,
+            });
+        }
+    }
+
+    let mut rows = bni.comments.iter().map(|c| c.start.line).peekable();
+    while let Some(start) = rows.next() {
+        let mut end = start;
+        while rows.next_if_eq(&(end + 1)).is_some() {
+            end += 1;
+        }
+        if end > start {
+            result.push(FoldingRange {
+                start_line: start,
+                end_line: end,
+                kind: Some(FoldingRangeKind::Comment),
+            });
+        }
+    }
+
+    result.sort_by_key(|f| f.start_line);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    #[test]
+    fn folds_sections_and_comment_runs() {
+        let text = "; units\n; of humans\n[hfoo]\nName=Footman\n// end of hfoo\n\n[hpea]\n[hkni]\nHp=800\n";
+        let document = Document::parse(&BACKEND, text);
+        let folds: Vec<_> = folding(&document.tree, &document.line_list)
+            .into_iter()
+            .map(|f| (f.start_line, f.end_line, f.kind))
+            .collect();
+        assert_eq!(
+            folds,
+            [
+                (0, 1, Some(FoldingRangeKind::Comment)),
+                (2, 4, None),
+                (7, 8, None),
+            ]
+        );
+    }
+}
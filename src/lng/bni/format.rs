@@ -0,0 +1,241 @@
+use crate::lng::bni::model::{self, Header, Item};
+use crate::lng::format::LineEdit;
+use crate::lsp::range::Range;
+use crate::util::line_list::LineList;
+use crate::util::settings::FormatSettings;
+use std::mem;
+use tree_sitter::Tree;
+
+/// What a line was found to be.
+enum Line<'a> {
+    Blank,
+    Header(&'a Header),
+    Item(&'a Item),
+    Comment,
+    /// Unparsable, kept as written.
+    Other,
+}
+
+/// Trims every line, writes `key=value` (or `key = value`), keeps one blank line between
+/// sections and none at their ends, and leaves comments where they are unless keys are
+/// sorted. With `range` only the sections it touches are formatted.
+pub fn format(
+    tree: &Tree,
+    line_list: &LineList,
+    settings: &FormatSettings,
+    range: Option<Range>,
+) -> Vec<LineEdit> {
+    let bni = model::read(tree, line_list);
+    let count = line_list.lines.len();
+
+    let mut lines: Vec<Line> = line_list
+        .lines
+        .iter()
+        .map(|line| match line.trim().is_empty() {
+            true => Line::Blank,
+            false => Line::Other,
+        })
+        .collect();
+    for comment in &bni.comments {
+        lines[comment.start.line] = Line::Comment;
+    }
+    for item in &bni.orphans {
+        lines[item.range.start.line] = Line::Item(item);
+    }
+    for section in &bni.sections {
+        lines[section.header.range.start.line] = Line::Header(&section.header);
+        for item in &section.items {
+            lines[item.range.start.line] = Line::Item(item);
+        }
+    }
+
+    // Whatever comes before the first header, then each section up to the next one.
+    let mut starts = vec![0];
+    starts.extend(bni.sections.iter().map(|s| s.header.range.start.line));
+    starts.dedup();
+
+    let mut edits = Vec::new();
+    for (i, &start) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).copied().unwrap_or(count);
+        if let Some(range) = range
+            && (end <= range.start.line || start > range.end.line)
+        {
+            continue;
+        }
+
+        let mut text = block(&lines[start..end], start, line_list, settings);
+        if !text.is_empty() && end < count {
+            text.push(String::new());
+        }
+        if text != line_list.lines[start..end] {
+            edits.push(LineEdit {
+                lines: start..end,
+                text,
+            });
+        }
+    }
+    edits
+}
+
+/// Formatted lines of one section, without blank lines around.
+fn block(
+    lines: &[Line],
+    first_row: usize,
+    line_list: &LineList,
+    settings: &FormatSettings,
+) -> Vec<String> {
+    let width = match settings.align_keys {
+        true => lines
+            .iter()
+            .filter_map(|line| match line {
+                Line::Item(item) => Some(item.key.chars().count()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0),
+        false => 0,
+    };
+    let text = |index: usize| {
+        let raw = &line_list.lines[first_row + index];
+        match lines[index] {
+            Line::Blank => None,
+            Line::Header(header) => Some(header_line(header, raw)),
+            Line::Item(item) => Some(item_line(item, raw, width, settings)),
+            Line::Comment => Some(raw.trim().to_string()),
+            Line::Other => Some(raw.trim_end().to_string()),
+        }
+    };
+
+    let rows: Vec<Option<String>> = if settings.sort_keys {
+        let head = matches!(lines.first(), Some(Line::Header(_))) as usize;
+        let mut entries: Vec<(String, Vec<String>)> = Vec::new();
+        let mut pending = Vec::new();
+        for (index, kind) in lines.iter().enumerate().skip(head) {
+            let Some(line) = text(index) else {
+                continue;
+            };
+            pending.push(line);
+            if let Line::Item(item) = kind {
+                entries.push((item.key.to_lowercase(), mem::take(&mut pending)));
+            }
+        }
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        (0..head)
+            .map(text)
+            .chain(entries.into_iter().flat_map(|(_, lines)| lines).map(Some))
+            .chain(pending.into_iter().map(Some))
+            .collect()
+    } else {
+        (0..lines.len()).map(text).collect()
+    };
+
+    let mut result: Vec<String> = Vec::new();
+    for row in rows {
+        match row {
+            Some(line) => result.push(line),
+            None if result.last().is_some_and(|l| !l.is_empty()) => result.push(String::new()),
+            None => {}
+        }
+    }
+    while result.last().is_some_and(String::is_empty) {
+        result.pop();
+    }
+    result
+}
+
+/// `[ Name ]  // note` -> `[Name] // note`
+fn header_line(header: &Header, raw: &str) -> String {
+    if !header.closed {
+        return raw.trim().to_string();
+    }
+    let tail = raw.find(']').map_or("", |close| raw[close + 1..].trim());
+    match tail.is_empty() {
+        true => format!("[{}]", header.name),
+        false => format!("[{}] {tail}", header.name),
+    }
+}
+
+fn item_line(item: &Item, raw: &str, width: usize, settings: &FormatSettings) -> String {
+    let value = raw[item.separator.character + 1..].trim();
+    let eq = match settings.space_around_equals {
+        true => " = ",
+        false => "=",
+    };
+    let line = format!("{:<width$}{eq}{value}", item.key);
+    line.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::format::apply;
+    use crate::lng::Document;
+    use crate::lsp::position::Position;
+
+    fn formatted(text: &str, settings: &FormatSettings, range: Option<Range>) -> String {
+        let document = Document::parse(&BACKEND, text);
+        let edits = format(&document.tree, &document.line_list, settings, range);
+        apply(&document.line_list, &edits).join("\n")
+    }
+
+    #[test]
+    fn tidies_lines_and_collapses_blank_lines() {
+        let text = "  [ hfoo ]  // note\n\n Name =  Footman \n\n\n[hpea]\n\n; c\nx = 1\n\n";
+        assert_eq!(
+            formatted(text, &FormatSettings::default(), None),
+            "[hfoo] // note\n\nName=Footman\n\n[hpea]\n\n; c\nx=1"
+        );
+    }
+
+    #[test]
+    fn aligns_keys_by_characters() {
+        let settings = FormatSettings {
+            align_keys: true,
+            space_around_equals: true,
+            ..Default::default()
+        };
+        let text = "[Юнит]\nИмя=Пехотинец\nHp=420\nMoveSpeed=270\n";
+        assert_eq!(
+            formatted(text, &settings, None),
+            "[Юнит]\nИмя       = Пехотинец\nHp        = 420\nMoveSpeed = 270"
+        );
+    }
+
+    #[test]
+    fn sorts_keys_with_their_comments() {
+        let settings = FormatSettings {
+            sort_keys: true,
+            ..Default::default()
+        };
+        let text = "[hfoo]\n// hit points\nHp=420\narmor=2\n\nName=Footman\n";
+        assert_eq!(
+            formatted(text, &settings, None),
+            "[hfoo]\narmor=2\n// hit points\nHp=420\nName=Footman"
+        );
+    }
+
+    #[test]
+    fn formats_only_sections_range_touches() {
+        let text = "[a]\nx =1\n\n[b]\ny =2\n";
+        let at = |line| Position { line, character: 0 };
+        let range = Range {
+            start: at(4),
+            end: at(4),
+        };
+        assert_eq!(
+            formatted(text, &FormatSettings::default(), Some(range)),
+            "[a]\nx =1\n\n[b]\ny=2"
+        );
+    }
+
+    #[test]
+    fn keeps_unparsable_lines() {
+        let text = "[a]\njunk line  \nx=1\n";
+        assert_eq!(
+            formatted(text, &FormatSettings::default(), None),
+            "[a]\njunk line\nx=1"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::lng::bni::model;
+use crate::lng::bni::schema::{self, Schema};
+use crate::lsp::hover::{Hover, MarkupContent};
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::util::line_list::LineList;
+use tree_sitter::Tree;
+use url::Url;
+
+/// Schema docs of the section name or of the key under the cursor, its value included.
+pub fn hover(tree: &Tree, line_list: &LineList, uri: &Url, position: Position) -> Option<Hover> {
+    let schema = schema::for_uri(uri)?;
+    describe(tree, line_list, &schema, position)
+}
+
+fn describe(
+    tree: &Tree,
+    line_list: &LineList,
+    schema: &Schema,
+    position: Position,
+) -> Option<Hover> {
+    let bni = model::read(tree, line_list);
+    let at = |range: &Range| {
+        range.contains(&Range {
+            start: position,
+            end: position,
+        })
+    };
+
+    let section = bni
+        .sections
+        .iter()
+        .rfind(|s| s.header.range.start.line <= position.line)?;
+    let section_schema = schema.section(section.name())?;
+
+    if at(&section.header.name_range) {
+        let mut text = format!("**[{}]**", section.name());
+        if let Some(doc) = &section_schema.doc {
+            text.push_str(&format!("\n\n{doc}"));
+        }
+        return Some(Hover {
+            contents: MarkupContent::markdown(text),
+            range: Some(section.header.name_range),
+        });
+    }
+
+    let item = section
+        .items
+        .iter()
+        .find(|i| at(&i.key_range) || at(&i.value_range))?;
+    let (name, key) = section_schema
+        .keys
+        .iter()
+        .find(|(name, _)| model::same_name(name, &item.key))?;
+    Some(Hover {
+        contents: MarkupContent::markdown(key.describe(name)),
+        range: Some(item.key_range),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::schema::tests::skin;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    fn hover_at(text: &str, line: usize, character: usize) -> Option<Hover> {
+        let document = Document::parse(&BACKEND, text);
+        let position = Position { line, character };
+        describe(&document.tree, &document.line_list, &skin(), position)
+    }
+
+    #[test]
+    fn shows_section_doc() {
+        let found = hover_at("[Units]\n", 0, 2).unwrap();
+        assert_eq!(found.contents.value, "**[Units]**\n\nUnit skins");
+    }
+
+    #[test]
+    fn shows_key_doc_on_key_and_value() {
+        let text = "[Units]\nname = Footman\n";
+        let on_key = hover_at(text, 1, 1).unwrap();
+        assert_eq!(
+            on_key.contents.value,
+            "**Name**: `string`\n\nShown in tooltips"
+        );
+        let on_value = hover_at(text, 1, 9).unwrap();
+        assert_eq!(on_value.range.unwrap().start.character, 0);
+    }
+
+    #[test]
+    fn skips_unknown_keys() {
+        assert!(hover_at("[Units]\nArmor=2\n", 1, 1).is_none());
+    }
+}
@@ -1,109 +1,107 @@
-use crate::lsp::semantic::TokenType;
-use crate::lsp::text_document::TextDocumentContentChangeEvent;
-use crate::util::uri_map::{UriMapEntry, URI_MAP};
-use log::info;
-use tree_sitter::{InputEdit, Parser};
-use url::Url;
-
-fn parse(entry: UriMapEntry) {
-    let tree = match entry.tree {
-        Some(ref t) => t,
-        None => return,
-    };
-
-    let root = tree.root_node();
-    let semantic = entry.semantic.clear();
-
-    for i in 0..root.child_count() {
-        let node = root.child(i).unwrap();
-        let s = node.start_position();
-        let e = node.end_position();
-
-        if s.row != e.row {
-            continue;
+pub mod action;
+pub mod completion;
+pub mod de;
+pub mod diagnostic;
+pub mod error;
+pub mod folding;
+pub mod format;
+pub mod hover;
+pub mod model;
+pub mod reference;
+pub mod schema;
+pub mod selection;
+pub mod ser;
+
+pub use de::{from_str, Deserializer};
+pub use error::{Error, Result};
+pub use ser::{to_string, to_string_preserving, Serializer};
+
+use crate::lng::bni::model::Item;
+use crate::lng::symbol::Symbol;
+use crate::lng::Backend;
+use crate::lsp::symbol::SymbolKind;
+use crate::util::line_list::LineList;
+use tree_sitter::Tree;
+
+pub static BACKEND: Backend = Backend {
+    id: "bni",
+    extensions: &["bni"],
+    language: || tree_sitter_bni::LANGUAGE.into(),
+    highlights: include_str!("../../../queries/bni/highlights.scm"),
+    symbols,
+    diagnostics: Some(diagnostic::diagnostics),
+    folding: Some(folding::folding),
+    selection: Some(selection::selection),
+    format: Some(format::format),
+    hover: Some(hover::hover),
+    completion: Some(completion::completion),
+    references: Some(reference::references),
+    rename: Some(reference::invalid_name),
+    actions: Some(action::actions),
+};
+
+/// Sections with their keys. Section range spans up to its last key, keys above
+/// the first section have no container.
+fn symbols(tree: &Tree, line_list: &LineList) -> Vec<Symbol> {
+    let bni = model::read(tree, line_list);
+    let mut result = Vec::new();
+
+    let keys = |items: &[Item], container: Option<&str>, result: &mut Vec<Symbol>| {
+        for item in items.iter().filter(|i| !i.key.is_empty()) {
+            result.push(Symbol {
+                name: item.key.clone(),
+                kind: SymbolKind::Property,
+                range: item.range,
+                selection_range: item.key_range,
+                container: container.map(str::to_string),
+            });
         }
+    };
 
-        match node.kind() {
-            "section" => {
-                semantic.add(
-                    s.row,
-                    s.column,
-                    e.column - s.column + 1,
-                    TokenType::Keyword,
-                    None,
-                );
-            }
-            "item" => {
-                semantic.add(
-                    s.row,
-                    s.column,
-                    e.column - s.column + 1,
-                    TokenType::String,
-                    None,
-                );
-            }
-            _ => {}
-        }
+    keys(&bni.orphans, None, &mut result);
+    for section in &bni.sections {
+        result.push(Symbol {
+            name: section.name().to_string(),
+            kind: SymbolKind::Namespace,
+            range: section.range(),
+            selection_range: section.header.name_range,
+            container: None,
+        });
+        keys(&section.items, Some(section.name()), &mut result);
     }
-}
-
-pub fn open(uri: &Url, text: impl AsRef<[u8]>) {
-    let mut map = URI_MAP.lock().unwrap();
-    let mut entry = map.entry(&uri);
-
-    let mut parser = Parser::new();
-    parser
-        .set_language(&tree_sitter_bni::LANGUAGE.into())
-        .expect("Error loading Bni parser");
 
-    let line_list = &mut entry.line_list;
-    line_list.set_text(&text);
-
-    entry.lng.replace("bni".to_string());
-    entry.tree.replace(parser.parse(&text, None).unwrap());
-    info!("open");
-
-    parse(entry);
+    result
 }
 
-pub fn change(uri: &Url, changes: Vec<TextDocumentContentChangeEvent>) {
-    let mut map = URI_MAP.lock().unwrap();
-    let entry = map.entry(uri);
-
-    let line_list = entry.line_list;
-    let tree = entry.tree.as_mut().unwrap();
-
-    for change in changes {
-        let range = &change.range;
-        let new_text = &change.text;
-
-        let start = &range.start;
-        let end = &range.end;
-
-        let start_byte = line_list.position_to_offset(start).unwrap();
-        let old_end_byte = line_list.position_to_offset(end).unwrap();
-
-        line_list.apply_change(start, end, new_text);
-
-        let new_end_byte = start_byte + new_text.len();
-
-        let edit = InputEdit {
-            start_byte,
-            old_end_byte,
-            new_end_byte,
-            start_position: start.point(),
-            old_end_position: end.point(),
-            new_end_position: line_list.point_from_offset(new_end_byte),
-        };
-        tree.edit(&edit);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::symbol::outline;
+    use crate::lng::Document;
+
+    #[test]
+    fn nests_keys_under_their_sections() {
+        let document = Document::parse(
+            &BACKEND,
+            "loose=1\n[hfoo]\nName=Footman\n=x\nHp=420\n\n[hpea]\n",
+        );
+        let roots = outline(&document.symbols());
+        let names: Vec<&str> = roots.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["loose", "hfoo", "hpea"]);
+
+        let hfoo = &roots[1];
+        assert_eq!(hfoo.kind, SymbolKind::Namespace);
+        assert_eq!((hfoo.range.start.line, hfoo.range.end.line), (1, 4));
+        let keys: Vec<&str> = hfoo.children.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(keys, ["Name", "Hp"]);
+        assert!(roots[2].children.is_empty());
     }
 
-    let mut parser = Parser::new();
-    parser
-        .set_language(&tree_sitter_bni::LANGUAGE.into())
-        .unwrap();
-    let new_text = line_list.to_text();
-    let new_tree = parser.parse(&new_text, None).unwrap();
-    entry.tree.replace(new_tree);
-    //parse(&uri);
+    #[test]
+    fn names_keys_by_their_section() {
+        let document = Document::parse(&BACKEND, "[hfoo]\nName=Footman\n");
+        let symbols = document.symbols();
+        assert_eq!(symbols[1].container.as_deref(), Some("hfoo"));
+        assert_eq!(symbols[1].selection_range.end.character, 4);
+    }
 }
@@ -0,0 +1,332 @@
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::util::line_list::LineList;
+use std::collections::{BTreeMap, HashMap};
+use tree_sitter::{Node, Tree};
+
+/// BNI file read line by line, with `section` and `item` nodes of the tree telling
+/// headers and keys apart. Everything BNI-specific works off this. Columns are bytes,
+/// as tree-sitter counts them; `lng::columns` turns them into the client's unit.
+#[derive(Debug, Default)]
+pub struct Bni {
+    /// Keys above the first header.
+    pub orphans: Vec<Item>,
+    pub sections: Vec<Section>,
+    /// Whole-line `//` and `;` comments.
+    pub comments: Vec<Range>,
+    /// Non-blank lines that are neither headers, keys nor comments.
+    pub errors: Vec<Range>,
+}
+
+#[derive(Debug)]
+pub struct Section {
+    pub header: Header,
+    pub items: Vec<Item>,
+}
+
+/// `[Name]`
+#[derive(Debug)]
+pub struct Header {
+    /// From `[` to the end of the node.
+    pub range: Range,
+    pub name: String,
+    pub name_range: Range,
+    /// Has the closing `]`.
+    pub closed: bool,
+}
+
+/// `key = value`
+#[derive(Debug)]
+pub struct Item {
+    pub range: Range,
+    pub key: String,
+    pub key_range: Range,
+    /// Position of `=`.
+    pub separator: Position,
+    pub value: String,
+    /// Empty right after `=` when there is no value.
+    pub value_range: Range,
+}
+
+impl Section {
+    pub fn name(&self) -> &str {
+        &self.header.name
+    }
+
+    /// From the header to the end of the last key.
+    pub fn range(&self) -> Range {
+        Range {
+            start: self.header.range.start,
+            end: self
+                .items
+                .last()
+                .map_or(self.header.range.end, |i| i.range.end),
+        }
+    }
+}
+
+impl Bni {
+    /// Lines of section `index`: from the header to the last non-blank line before the
+    /// next one, so trailing comments belong to the section above them.
+    pub fn extent(&self, index: usize, line_list: &LineList) -> Range {
+        let start = self.sections[index].header.range.start;
+        let next = self
+            .sections
+            .get(index + 1)
+            .map_or(line_list.lines.len(), |s| s.header.range.start.line);
+        let last = (start.line..next)
+            .rev()
+            .find(|&row| !line_list.lines[row].trim().is_empty())
+            .unwrap_or(start.line);
+        Range {
+            start,
+            end: Position {
+                line: last,
+                character: line_list.lines[last].trim_end().len(),
+            },
+        }
+    }
+}
+
+/// BNI names are case-insensitive, as the game reads them.
+pub fn same_name(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+/// Index of the first of the same name for each of `names`, its own index if it is the first.
+pub fn first_of_name<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<usize> {
+    let mut first = HashMap::new();
+    names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| *first.entry(name.to_ascii_lowercase()).or_insert(i))
+        .collect()
+}
+
+pub fn is_comment(text: &str) -> bool {
+    let text = text.trim_start();
+    text.starts_with("//") || text.starts_with(';')
+}
+
+/// Elements of a comma list and the commas between them, with byte offsets.
+/// Commas inside double quotes are text.
+pub fn split(value: &str) -> Vec<(usize, &str)> {
+    let mut result = Vec::new();
+    let mut quoted = false;
+    let mut from = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                result.push((from, &value[from..i]));
+                result.push((i, ","));
+                from = i + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push((from, &value[from..]));
+    result
+}
+
+/// Four character object id: quoted in single quotes, or bare with at least one digit.
+pub fn is_rawcode(text: &str) -> bool {
+    let alnum = |s: &str| s.len() == 4 && s.bytes().all(|b| b.is_ascii_alphanumeric());
+    match text.strip_prefix('\'').and_then(|t| t.strip_suffix('\'')) {
+        Some(inner) => alnum(inner),
+        None => alnum(text) && text.bytes().any(|b| b.is_ascii_digit()),
+    }
+}
+
+pub fn read(tree: &Tree, line_list: &LineList) -> Bni {
+    let nodes = line_nodes(tree);
+    let mut bni = Bni::default();
+
+    for (row, line) in line_list.lines.iter().enumerate() {
+        let node = nodes.get(&row);
+        let column = node.map_or(0, |n| n.start_position().column);
+        let end = match node {
+            Some(n) if n.end_position().row == row => n.end_position().column,
+            _ => line.len(),
+        };
+        let text = line.get(column.min(end)..end).unwrap_or("");
+
+        match node.map(|n| n.kind()) {
+            Some("section") => {
+                let header = Header::parse(row, column, text);
+                bni.sections.push(Section {
+                    header,
+                    items: Vec::new(),
+                });
+            }
+            Some("item") => match Item::parse(row, column, text) {
+                Some(item) => match bni.sections.last_mut() {
+                    Some(section) => section.items.push(item),
+                    None => bni.orphans.push(item),
+                },
+                None => bni.errors.push(trimmed_range(row, column, text)),
+            },
+            _ if line.trim().is_empty() => {}
+            _ if is_comment(line) || node.is_some_and(|n| n.kind() == "comment") => {
+                bni.comments.push(trimmed_range(row, 0, line));
+            }
+            _ => bni.errors.push(trimmed_range(row, 0, line)),
+        }
+    }
+
+    bni
+}
+
+/// First `section`, `item` or `comment` node starting on each line, at any depth,
+/// so that lines the parser recovered inside ERROR still count.
+fn line_nodes(tree: &Tree) -> BTreeMap<usize, Node<'_>> {
+    let mut result = BTreeMap::new();
+    let mut cursor = tree.walk();
+
+    loop {
+        let node = cursor.node();
+        let claimed = matches!(node.kind(), "section" | "item" | "comment");
+        if claimed {
+            result.entry(node.start_position().row).or_insert(node);
+        }
+
+        if !claimed && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return result;
+            }
+        }
+    }
+}
+
+fn range(row: usize, start: usize, end: usize) -> Range {
+    Range {
+        start: Position {
+            line: row,
+            character: start,
+        },
+        end: Position {
+            line: row,
+            character: end,
+        },
+    }
+}
+
+/// Range of `text` at `column` without surrounding whitespace.
+fn trimmed_range(row: usize, column: usize, text: &str) -> Range {
+    let start = column + text.len() - text.trim_start().len();
+    let end = column + text.trim_end().len();
+    range(row, start, end.max(start))
+}
+
+impl Header {
+    fn parse(row: usize, column: usize, text: &str) -> Self {
+        let range = trimmed_range(row, column, text);
+        let open = text.find('[').map_or(0, |i| i + 1);
+        let rest = &text[open..];
+        let (inner, closed) = match rest.find(']') {
+            Some(close) => (&rest[..close], true),
+            None => (rest, false),
+        };
+        let name_range = trimmed_range(row, column + open, inner);
+
+        Self {
+            range,
+            name: inner.trim().to_string(),
+            name_range,
+            closed,
+        }
+    }
+}
+
+impl Item {
+    /// `None` without `=`.
+    fn parse(row: usize, column: usize, text: &str) -> Option<Self> {
+        let eq = text.find('=')?;
+        let key = &text[..eq];
+        let value = &text[eq + 1..];
+
+        let mut value_range = trimmed_range(row, column + eq + 1, value);
+        if value.trim().is_empty() {
+            value_range.start.character = column + eq + 1;
+            value_range.end = value_range.start;
+        }
+
+        Some(Self {
+            range: trimmed_range(row, column, text),
+            key: key.trim().to_string(),
+            key_range: trimmed_range(row, column, key),
+            separator: Position {
+                line: row,
+                character: column + eq,
+            },
+            value: value.trim().to_string(),
+            value_range,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    fn bni(text: &str) -> Bni {
+        let document = Document::parse(&BACKEND, text);
+        read(&document.tree, &document.line_list)
+    }
+
+    #[test]
+    fn reads_lines_into_sections() {
+        let bni = bni("loose = 1\n; note\n[ hfoo ]\nName = Footman\n\n[hpea\nx\n");
+        assert_eq!(bni.orphans[0].key, "loose");
+        assert_eq!(bni.comments.len(), 1);
+        assert_eq!(bni.sections.len(), 2);
+
+        let hfoo = &bni.sections[0];
+        assert_eq!(hfoo.name(), "hfoo");
+        assert_eq!(hfoo.header.name_range.start.character, 2);
+        assert_eq!(hfoo.items[0].value, "Footman");
+        assert_eq!(hfoo.items[0].separator.character, 5);
+
+        assert!(!bni.sections[1].header.closed);
+        assert_eq!(bni.errors.len(), 1);
+        assert_eq!(bni.errors[0].start.line, 6);
+    }
+
+    #[test]
+    fn extends_sections_over_trailing_comments() {
+        let text = "[a]\nk=1\n// about b\n\n[b]\n";
+        let document = Document::parse(&BACKEND, text);
+        let bni = read(&document.tree, &document.line_list);
+        assert_eq!(bni.extent(0, &document.line_list).end.line, 2);
+        assert_eq!(bni.extent(1, &document.line_list).end.line, 4);
+    }
+
+    #[test]
+    fn splits_commas_outside_quotes() {
+        assert_eq!(
+            split("a, \"b,c\",d"),
+            [(0, "a"), (1, ","), (2, " \"b,c\""), (8, ","), (9, "d")]
+        );
+    }
+
+    #[test]
+    fn tells_rawcodes() {
+        assert!(is_rawcode("'hfoo'"));
+        assert!(is_rawcode("h00A"));
+        assert!(!is_rawcode("hfoo"));
+        assert!(!is_rawcode("'hfo'"));
+    }
+
+    #[test]
+    fn finds_first_of_each_name() {
+        assert_eq!(
+            first_of_name(["Name", "Hp", "name", "NAME", "Art"]),
+            [0, 1, 0, 0, 4]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::lng::bni::de::unquote;
+use crate::lng::bni::model::{self, Item};
+use crate::lng::bni::schema::glob;
+use crate::lng::symbol::Occurrence;
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::util::line_list::LineList;
+use crate::util::settings::Settings;
+use tree_sitter::Tree;
+
+/// Section names of headers, and elements of the values of keys `jass.bni.referenceKeys`
+/// picks, quotes left out. Names compare without ASCII case.
+pub fn references(tree: &Tree, line_list: &LineList, settings: &Settings) -> Vec<Occurrence> {
+    let bni = model::read(tree, line_list);
+    let rules = &settings.bni.reference_keys;
+    let mut result = Vec::new();
+
+    for item in &bni.orphans {
+        if holds_reference(rules, None, &item.key) {
+            elements(item, &mut result);
+        }
+    }
+    for section in &bni.sections {
+        if !section.name().is_empty() {
+            result.push(Occurrence {
+                key: section.name().to_ascii_lowercase(),
+                range: section.header.name_range,
+                declaration: true,
+            });
+        }
+        for item in &section.items {
+            if holds_reference(rules, Some(section.name()), &item.key) {
+                elements(item, &mut result);
+            }
+        }
+    }
+
+    result
+}
+
+/// Section names end at `]`, and values at `,` once they are referenced.
+pub fn invalid_name(name: &str) -> Option<String> {
+    if name.trim().is_empty() {
+        return Some("Section name can't be empty".into());
+    }
+    if name.trim() != name {
+        return Some("Section name can't start or end with spaces".into());
+    }
+    if name.contains(['[', ']', '=', ',', '"', '\n', '\r']) {
+        return Some(format!(
+            "`{name}` can't be a section name, it has one of `[`, `]`, `=`, `,` or `\"`"
+        ));
+    }
+    None
+}
+
+/// `Key` rules pick keys of any section, `Section.Key` ones keys of matching sections.
+fn holds_reference(rules: &[String], section: Option<&str>, key: &str) -> bool {
+    rules.iter().any(|rule| match rule.rsplit_once('.') {
+        Some((s, k)) => section.is_some_and(|section| glob(s, section)) && glob(k, key),
+        None => glob(rule, key),
+    })
+}
+
+fn elements(item: &Item, result: &mut Vec<Occurrence>) {
+    let row = item.value_range.start.line;
+    let start = item.value_range.start.character;
+
+    for (offset, part) in model::split(&item.value) {
+        let text = part.trim();
+        let rawcode = text.strip_prefix('\'').and_then(|t| t.strip_suffix('\''));
+        let (quote, name) = match rawcode {
+            Some(inner) if model::is_rawcode(text) => (1, inner),
+            _ => unquote(text),
+        };
+        if part == "," || name.is_empty() {
+            continue;
+        }
+        let column = start + offset + (part.len() - part.trim_start().len()) + quote;
+        result.push(Occurrence {
+            key: name.to_ascii_lowercase(),
+            range: Range {
+                start: Position {
+                    line: row,
+                    character: column,
+                },
+                end: Position {
+                    line: row,
+                    character: column + name.len(),
+                },
+            },
+            declaration: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::{index, reference, session, Document};
+    use crate::lsp::position::PositionEncoding;
+    use crate::util::lock::Lock;
+    use crate::util::uri_map::URI_MAP;
+    use url::Url;
+
+    fn settings(rules: &[&str]) -> Settings {
+        let mut settings = Settings::default();
+        settings.bni.reference_keys = rules.iter().map(|r| r.to_string()).collect();
+        settings
+    }
+
+    /// `(key, line, start, end, declaration)` of each occurrence.
+    fn found(text: &str, rules: &[&str]) -> Vec<(String, usize, usize, usize, bool)> {
+        let document = Document::parse(&BACKEND, text);
+        references(&document.tree, &document.line_list, &settings(rules))
+            .into_iter()
+            .map(|o| {
+                let (start, end) = (o.range.start, o.range.end);
+                (
+                    o.key,
+                    start.line,
+                    start.character,
+                    end.character,
+                    o.declaration,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_only_headers_by_default() {
+        assert_eq!(
+            found("[HFoo]\nUpgrade=hpea\n", &[]),
+            [("hfoo".to_string(), 0, 1, 5, true)]
+        );
+    }
+
+    #[test]
+    fn finds_list_elements_past_quotes() {
+        let text = "[hfoo]\nUpgrade = 'hpea', \"hkni\" ,h00A\n";
+        assert_eq!(
+            found(text, &["Upgrade"]),
+            [
+                ("hfoo".to_string(), 0, 1, 5, true),
+                ("hpea".to_string(), 1, 11, 15, false),
+                ("hkni".to_string(), 1, 19, 23, false),
+                ("h00a".to_string(), 1, 26, 30, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_rules_by_section_and_key() {
+        let rules = ["Units.Up*", "Requires"];
+        assert!(holds_reference(
+            &rules.map(String::from),
+            Some("units"),
+            "upgrade"
+        ));
+        assert!(!holds_reference(
+            &rules.map(String::from),
+            Some("items"),
+            "upgrade"
+        ));
+        assert!(!holds_reference(&rules.map(String::from), None, "upgrade"));
+        assert!(holds_reference(&rules.map(String::from), None, "requires"));
+        assert!(holds_reference(
+            &rules.map(String::from),
+            Some("items"),
+            "Requires"
+        ));
+    }
+
+    #[test]
+    fn rejects_names_headers_cant_hold() {
+        assert_eq!(invalid_name("hkni"), None);
+        assert!(invalid_name(" ").is_some());
+        assert!(invalid_name(" hkni").is_some());
+        assert!(invalid_name("a]b").is_some());
+        assert!(invalid_name("a,b").is_some());
+    }
+
+    #[test]
+    fn finds_name_across_files() {
+        let a = Url::parse("file:///jass-reference-test/a.bni").unwrap();
+        let b = Url::parse("file:///jass-reference-test/b.bni").unwrap();
+        session::open(&a, &BACKEND, "[xref]\nName=X\n");
+        session::open(&b, &BACKEND, "[other]\nUpgrade=xref\nTip=xref\n");
+
+        let found: Vec<(Url, usize, bool)> = reference::find(
+            &BACKEND,
+            "xref",
+            &settings(&["Upgrade"]),
+            PositionEncoding::Utf8,
+        )
+        .into_iter()
+        .map(|(uri, o)| (uri, o.range.start.line, o.declaration))
+        .collect();
+        assert_eq!(found, [(a.clone(), 0, true), (b.clone(), 1, false)]);
+        assert!(
+            reference::find(&BACKEND, "x", &settings(&["Name"]), PositionEncoding::Utf8).is_empty()
+        );
+
+        for uri in [a, b] {
+            URI_MAP.locked().remove(&uri);
+            index::remove(&uri);
+        }
+    }
+}
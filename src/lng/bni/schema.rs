@@ -0,0 +1,399 @@
+use crate::lng::bni::model;
+use crate::lng::Document;
+use crate::util::lock::Lock;
+use crate::util::workspace::WORKSPACE;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// Schemas of the workspace, read on first use.
+static SCHEMAS: Lazy<Mutex<Option<Vec<Arc<Schema>>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Sections, keys and values allowed in one BNI dialect.
+///
+/// Read from `<root>/.jass/schemas/*.json`:
+///
+/// ```json
+/// {
+///   "files": ["war3mapSkin.bni", "*Skin.bni"],
+///   "sections": {
+///     "Units": {
+///       "doc": "Unit skins",
+///       "keys": {
+///         "Name": { "type": "string", "doc": "Shown in tooltips" },
+///         "Kind": { "type": "enum", "values": ["melee", "ranged"], "list": true }
+///       }
+///     }
+///   }
+/// }
+/// ```
+///
+/// or from `<root>/.jass/schemas/*.bni`, with `[schema]` holding `files`, `[Section]`
+/// holding `doc` and `[Section.Key]` holding `type`, `values`, `list` and `doc`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct Schema {
+    /// File names the dialect applies to, `*` and `?` wildcards, case-insensitive.
+    pub files: Vec<String>,
+    /// By section name, `*` stands for sections not listed.
+    pub sections: BTreeMap<String, SectionSchema>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct SectionSchema {
+    pub doc: Option<String>,
+    pub keys: BTreeMap<String, KeySchema>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct KeySchema {
+    #[serde(rename = "type")]
+    pub value_type: ValueType,
+    /// Comma separated values, each of `value_type`.
+    pub list: bool,
+    /// Allowed values of `enum`.
+    pub values: Vec<String>,
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueType {
+    #[default]
+    String,
+    Integer,
+    Real,
+    /// `true`, `false`, `1` or `0`.
+    Boolean,
+    /// Four character object id, bare or in single quotes.
+    Rawcode,
+    Enum,
+}
+
+impl ValueType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ValueType::String => "string",
+            ValueType::Integer => "integer",
+            ValueType::Real => "real",
+            ValueType::Boolean => "boolean",
+            ValueType::Rawcode => "rawcode",
+            ValueType::Enum => "enum",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        [
+            ValueType::String,
+            ValueType::Integer,
+            ValueType::Real,
+            ValueType::Boolean,
+            ValueType::Rawcode,
+            ValueType::Enum,
+        ]
+        .into_iter()
+        .find(|t| t.name().eq_ignore_ascii_case(name))
+    }
+}
+
+impl Schema {
+    /// Schema of the section, the `*` one for sections not listed.
+    pub fn section(&self, name: &str) -> Option<&SectionSchema> {
+        let find = |name: &str| {
+            self.sections
+                .iter()
+                .find(|(n, _)| model::same_name(n, name))
+                .map(|(_, s)| s)
+        };
+        find(name).or_else(|| find("*"))
+    }
+
+    fn applies_to(&self, file_name: &str) -> bool {
+        self.files.iter().any(|pattern| glob(pattern, file_name))
+    }
+
+    /// `[schema]`, `[Section]` and `[Section.Key]` sections of a BNI schema file.
+    fn from_bni(text: &str) -> Self {
+        let document = Document::parse(&super::BACKEND, text);
+        let bni = model::read(&document.tree, &document.line_list);
+        let mut schema = Schema::default();
+
+        for section in &bni.sections {
+            let value = |key: &str| {
+                section
+                    .items
+                    .iter()
+                    .find(|i| model::same_name(&i.key, key))
+                    .map(|i| i.value.clone())
+            };
+            let list = |key: &str| {
+                value(key).map_or(Vec::new(), |v| {
+                    v.split(',').map(|s| s.trim().to_string()).collect()
+                })
+            };
+
+            if model::same_name(section.name(), "schema") {
+                schema.files = list("files");
+                continue;
+            }
+            match section.name().split_once('.') {
+                Some((name, key)) => {
+                    let value_type =
+                        value("type").map_or(Some(ValueType::String), |t| ValueType::parse(&t));
+                    let Some(value_type) = value_type else {
+                        warn!("Unknown type in schema section [{}]", section.name());
+                        continue;
+                    };
+                    let entry = schema.sections.entry(name.to_string()).or_default();
+                    entry.keys.insert(
+                        key.to_string(),
+                        KeySchema {
+                            value_type,
+                            list: value("list")
+                                .is_some_and(|v| matches!(v.to_lowercase().as_str(), "true" | "1")),
+                            values: list("values"),
+                            doc: value("doc"),
+                        },
+                    );
+                }
+                None => {
+                    let entry = schema
+                        .sections
+                        .entry(section.name().to_string())
+                        .or_default();
+                    entry.doc = value("doc");
+                }
+            }
+        }
+        schema
+    }
+
+    fn load(path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path)
+            .inspect_err(|err| warn!("Can't read schema {:?}: {}", path, err))
+            .ok()?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::from_str(&text)
+                .inspect_err(|err| warn!("Invalid schema {:?}: {}", path, err))
+                .ok(),
+            Some("bni") => Some(Self::from_bni(&text)),
+            _ => None,
+        }
+    }
+}
+
+impl KeySchema {
+    /// Whether one value, or one element of a list, fits the type.
+    pub fn accepts(&self, value: &str) -> bool {
+        match self.value_type {
+            ValueType::String => true,
+            ValueType::Integer => value.parse::<i64>().is_ok(),
+            ValueType::Real => value.parse::<f64>().is_ok_and(f64::is_finite),
+            ValueType::Boolean => ["true", "false", "1", "0"]
+                .iter()
+                .any(|b| b.eq_ignore_ascii_case(value)),
+            ValueType::Rawcode => {
+                model::is_rawcode(value) || model::is_rawcode(&format!("'{value}'"))
+            }
+            ValueType::Enum => self.values.iter().any(|v| model::same_name(v, value)),
+        }
+    }
+
+    /// `integer list`, `enum` and so on.
+    pub fn type_name(&self) -> String {
+        match self.list {
+            true => format!("{} list", self.value_type.name()),
+            false => self.value_type.name().to_string(),
+        }
+    }
+
+    /// Markdown for hover and completion.
+    pub fn describe(&self, key: &str) -> String {
+        let mut text = format!("**{key}**: `{}`", self.type_name());
+        if let Some(doc) = &self.doc {
+            text.push_str(&format!("\n\n{doc}"));
+        }
+        if !self.values.is_empty() {
+            let values: Vec<String> = self.values.iter().map(|v| format!("`{v}`")).collect();
+            text.push_str(&format!("\n\nOne of {}", values.join(", ")));
+        }
+        text
+    }
+}
+
+/// Drops loaded schemas, the next lookup reads `.jass/schemas` again.
+pub fn reset() {
+    *SCHEMAS.locked() = None;
+}
+
+/// Schema whose `files` match the file name of `uri`, the first one found.
+pub fn for_uri(uri: &Url) -> Option<Arc<Schema>> {
+    find(SCHEMAS.locked().get_or_insert_with(load_all), uri)
+}
+
+fn find(schemas: &[Arc<Schema>], uri: &Url) -> Option<Arc<Schema>> {
+    let file_name = uri.path_segments()?.next_back()?;
+    schemas.iter().find(|s| s.applies_to(file_name)).cloned()
+}
+
+/// `*.json` and `*.bni` of `<root>/.jass/schemas`, in file name order.
+fn load_all() -> Vec<Arc<Schema>> {
+    let Some(dir) = WORKSPACE.locked().override_path("schemas") else {
+        return Vec::new();
+    };
+    let Ok(read) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = read.filter_map(|e| Some(e.ok()?.path())).collect();
+    paths.sort();
+
+    let schemas: Vec<Arc<Schema>> = paths
+        .iter()
+        .filter_map(|path| Schema::load(path))
+        .map(Arc::new)
+        .collect();
+    info!("{} BNI schemas from {:?}", schemas.len(), dir);
+    schemas
+}
+
+/// `*` any run of characters, `?` any one, letters compared without case.
+pub fn glob(pattern: &str, text: &str) -> bool {
+    let (p, t): (Vec<char>, Vec<char>) = (pattern.chars().collect(), text.chars().collect());
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi].eq_ignore_ascii_case(&t[ti])) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    const SKIN: &str = "\
+[schema]
+files = *Skin.bni
+
+[Units]
+doc = Unit skins
+
+[Units.Name]
+doc = Shown in tooltips
+
+[Units.Kind]
+type = enum
+values = melee, ranged
+list = true
+
+[Units.Hidden]
+type = boolean
+";
+
+    /// Schema of `*Skin.bni` files, for checks that take one.
+    pub(crate) fn skin() -> Schema {
+        Schema::from_bni(SKIN)
+    }
+
+    #[test]
+    fn globs_without_case() {
+        assert!(glob("*Skin.bni", "war3mapskin.BNI"));
+        assert!(glob("unit??.bni", "unit01.bni"));
+        assert!(glob("*", ""));
+        assert!(glob("a*b*c", "aXbYbc"));
+        assert!(!glob("unit?.bni", "unit01.bni"));
+        assert!(!glob("*.bni", "units.ini"));
+    }
+
+    #[test]
+    fn reads_bni_schema() {
+        let schema = Schema::from_bni(SKIN);
+        assert_eq!(schema.files, ["*Skin.bni"]);
+        let units = schema.section("units").unwrap();
+        assert_eq!(units.doc.as_deref(), Some("Unit skins"));
+        assert_eq!(units.keys["Name"].value_type, ValueType::String);
+        let kind = &units.keys["Kind"];
+        assert_eq!(kind.value_type, ValueType::Enum);
+        assert!(kind.list);
+        assert_eq!(kind.values, ["melee", "ranged"]);
+        assert_eq!(kind.type_name(), "enum list");
+        assert!(schema.section("hfoo").is_none());
+    }
+
+    #[test]
+    fn reads_json_schema() {
+        let schema: Schema = serde_json::from_str(
+            r#"{ "files": ["a.bni"], "sections": { "A": { "keys": { "N": { "type": "integer" } } }, "*": {} } }"#,
+        )
+        .unwrap();
+        assert!(schema.applies_to("A.bni"));
+        assert_eq!(
+            schema.section("a").unwrap().keys["N"].value_type,
+            ValueType::Integer
+        );
+        assert!(schema.section("B").unwrap().keys.is_empty());
+    }
+
+    #[test]
+    fn checks_values_by_type() {
+        let key = |value_type| KeySchema {
+            value_type,
+            values: vec!["Melee".into()],
+            ..Default::default()
+        };
+        assert!(key(ValueType::Integer).accepts("-12"));
+        assert!(!key(ValueType::Integer).accepts("1.5"));
+        assert!(key(ValueType::Real).accepts("1.5"));
+        assert!(!key(ValueType::Real).accepts("inf"));
+        assert!(key(ValueType::Boolean).accepts("TRUE"));
+        assert!(!key(ValueType::Boolean).accepts("yes"));
+        assert!(key(ValueType::Rawcode).accepts("hfoo"));
+        assert!(key(ValueType::Rawcode).accepts("'hfoo'"));
+        assert!(!key(ValueType::Rawcode).accepts("footman"));
+        assert!(key(ValueType::Enum).accepts("melee"));
+        assert!(!key(ValueType::Enum).accepts("ranged"));
+    }
+
+    #[test]
+    fn describes_key() {
+        let key = KeySchema {
+            value_type: ValueType::Enum,
+            values: vec!["melee".into(), "ranged".into()],
+            doc: Some("Attack kind".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            key.describe("Kind"),
+            "**Kind**: `enum`\n\nAttack kind\n\nOne of `melee`, `ranged`"
+        );
+    }
+
+    #[test]
+    fn finds_schema_by_file_name() {
+        let schemas = [Arc::new(Schema::default()), Arc::new(skin())];
+        let uri = |path| Url::parse(path).unwrap();
+        let found = find(&schemas, &uri("file:///map/war3mapSkin.bni")).unwrap();
+        assert_eq!(found.files, ["*Skin.bni"]);
+        assert!(find(&schemas, &uri("file:///map/units.bni")).is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::lng::bni::model::{self, Bni};
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::lsp::selection::SelectionRange;
+use crate::util::line_list::LineList;
+use tree_sitter::Tree;
+
+/// value -> `key=value` line -> section -> file, headers go name -> `[name]` -> section.
+pub fn selection(tree: &Tree, line_list: &LineList, positions: &[Position]) -> Vec<SelectionRange> {
+    let bni = model::read(tree, line_list);
+    let last = line_list.lines.len().saturating_sub(1);
+    let file = Range {
+        start: Position {
+            line: 0,
+            character: 0,
+        },
+        end: Position {
+            line: last,
+            character: line_list.lines.get(last).map_or(0, |l| l.len()),
+        },
+    };
+
+    positions
+        .iter()
+        .filter_map(|position| SelectionRange::nest(ranges(&bni, line_list, *position, file)))
+        .collect()
+}
+
+/// Ranges around `position`, innermost first.
+fn ranges(bni: &Bni, line_list: &LineList, position: Position, file: Range) -> Vec<Range> {
+    let at = |range: &Range| {
+        range.contains(&Range {
+            start: position,
+            end: position,
+        })
+    };
+    let mut result = Vec::new();
+
+    let section = bni
+        .sections
+        .iter()
+        .rposition(|s| s.header.range.start.line <= position.line);
+    let items = match section {
+        Some(index) => &bni.sections[index].items,
+        None => &bni.orphans,
+    };
+
+    if let Some(item) = items.iter().find(|i| i.range.start.line == position.line) {
+        for range in [item.key_range, item.value_range] {
+            if range.start != range.end && at(&range) {
+                result.push(range);
+            }
+        }
+        result.push(item.range);
+    }
+
+    if let Some(index) = section {
+        let header = &bni.sections[index].header;
+        if header.range.start.line == position.line {
+            if at(&header.name_range) {
+                result.push(header.name_range);
+            }
+            result.push(header.range);
+        }
+        let extent = bni.extent(index, line_list);
+        if at(&extent) {
+            result.push(extent);
+        }
+    }
+
+    result.push(file);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    /// `(line, start, end line, end)` of each range, innermost first.
+    fn chain(text: &str, line: usize, character: usize) -> Vec<(usize, usize, usize, usize)> {
+        let document = Document::parse(&BACKEND, text);
+        let position = Position { line, character };
+        let found = selection(&document.tree, &document.line_list, &[position]);
+        let mut result = Vec::new();
+        let mut next = found.first();
+        while let Some(selection) = next {
+            let r = selection.range;
+            result.push((r.start.line, r.start.character, r.end.line, r.end.character));
+            next = selection.parent.as_deref();
+        }
+        result
+    }
+
+    const TEXT: &str = "[hfoo]\nName = Footman\nHp=420\n\n[hpea]\n";
+
+    #[test]
+    fn expands_from_value_to_file() {
+        assert_eq!(
+            chain(TEXT, 1, 9),
+            [(1, 7, 1, 14), (1, 0, 1, 14), (0, 0, 2, 6), (0, 0, 4, 6)]
+        );
+    }
+
+    #[test]
+    fn expands_from_header_name() {
+        assert_eq!(
+            chain(TEXT, 0, 2),
+            [(0, 1, 0, 5), (0, 0, 0, 6), (0, 0, 2, 6), (0, 0, 4, 6)]
+        );
+    }
+}
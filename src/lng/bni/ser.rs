@@ -0,0 +1,814 @@
+use crate::lng::bni::de::unquote;
+use crate::lng::bni::error::{Error, Result};
+use crate::lng::bni::model::{self, Item};
+use crate::lng::{format, Document};
+use serde::ser::{self, Impossible, Serialize};
+
+/// Keys with their values written out, in the order they were serialized.
+pub type Keys = Vec<(String, String)>;
+
+/// What a value serializes to, before it is written as text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Data {
+    /// Entries that are no sections, written above the first header.
+    pub orphans: Keys,
+    pub sections: Vec<(String, Keys)>,
+}
+
+/// Writes `value` as BNI, the mirror of [`super::de::from_str`]: maps and structs of the
+/// top level become sections, other entries keys above them, sequences comma lists.
+/// `None` leaves a key out.
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    Ok(write(&value.serialize(Serializer)?))
+}
+
+/// Like [`to_string`], but written over `original`, the text `value` was read from:
+/// comments, blank lines, order and the spelling of unchanged values stay as they are.
+/// Keys and sections `value` no longer has are removed, new ones are added after the
+/// last key of their section or as new sections at the end.
+pub fn to_string_preserving<T: Serialize + ?Sized>(original: &str, value: &T) -> Result<String> {
+    let data = value.serialize(Serializer)?;
+    if original.trim().is_empty() {
+        return Ok(write(&data));
+    }
+    Ok(merge(original, &data))
+}
+
+/// Text of `data` as the formatter would write it.
+fn write(data: &Data) -> String {
+    let mut lines: Vec<String> = data
+        .orphans
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect();
+    for (name, keys) in &data.sections {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("[{name}]"));
+        lines.extend(keys.iter().map(|(k, v)| format!("{k}={v}")));
+    }
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Lines of `original` with values, keys and sections changed to those of `data`.
+fn merge(original: &str, data: &Data) -> String {
+    let document = Document::parse(&super::BACKEND, original);
+    let bni = model::read(&document.tree, &document.line_list);
+    let lines = &document.line_list.lines;
+
+    // What each original line turns into, none for removed ones.
+    let mut rows: Vec<Vec<String>> = lines.iter().map(|line| vec![line.clone()]).collect();
+
+    let orphans: Vec<&Item> = bni.orphans.iter().collect();
+    let added = update(&orphans, &data.orphans, lines, &mut rows);
+    if !added.is_empty() {
+        match bni.orphans.last() {
+            Some(last) => rows[last.range.start.line].extend(added),
+            None => {
+                let mut head = added;
+                head.push(String::new());
+                rows[0].splice(0..0, head);
+            }
+        }
+    }
+
+    let mut matched = vec![false; data.sections.len()];
+    for (index, section) in bni.sections.iter().enumerate() {
+        let header = section.header.range.start.line;
+        let wanted = data
+            .sections
+            .iter()
+            .position(|(name, _)| model::same_name(name, section.name()));
+        let Some(wanted) = wanted else {
+            let next = bni
+                .sections
+                .get(index + 1)
+                .map_or(lines.len(), |s| s.header.range.start.line);
+            rows[header..next].iter_mut().for_each(Vec::clear);
+            continue;
+        };
+
+        if matched[wanted] {
+            continue;
+        }
+        matched[wanted] = true;
+
+        // Every section of the name at once, keys missing from all of them go to the first.
+        let items: Vec<&Item> = bni.sections[index..]
+            .iter()
+            .filter(|s| model::same_name(s.name(), section.name()))
+            .flat_map(|s| &s.items)
+            .collect();
+        let added = update(&items, &data.sections[wanted].1, lines, &mut rows);
+        let anchor = section.items.last().map_or(header, |i| i.range.start.line);
+        rows[anchor].extend(added);
+    }
+
+    let mut result: Vec<String> = rows.into_iter().flatten().collect();
+    let sections = data.sections.iter().zip(matched).filter(|(_, m)| !m);
+    for ((name, keys), _) in sections {
+        while result.last().is_some_and(|l| l.trim().is_empty()) {
+            result.pop();
+        }
+        if !result.is_empty() {
+            result.push(String::new());
+        }
+        result.push(format!("[{name}]"));
+        result.extend(keys.iter().map(|(k, v)| format!("{k}={v}")));
+    }
+    while result.last().is_some_and(|l| l.trim().is_empty()) {
+        result.pop();
+    }
+    format::join(&result, original)
+}
+
+/// Rewrites values of `items` that changed and clears the lines of keys `keys` lacks.
+/// Returns lines for the keys no item has, in the style of the first item.
+fn update(items: &[&Item], keys: &Keys, lines: &[String], rows: &mut [Vec<String>]) -> Vec<String> {
+    for item in items {
+        let row = item.range.start.line;
+        match keys.iter().find(|(k, _)| model::same_name(k, &item.key)) {
+            None => rows[row].clear(),
+            Some((_, value)) if !same_value(&item.value, value) => {
+                let line = &lines[row];
+                rows[row] = vec![format!(
+                    "{}{value}{}",
+                    &line[..item.value_range.start.character],
+                    &line[item.value_range.end.character..]
+                )];
+            }
+            Some(_) => {}
+        }
+    }
+
+    let separator = items.first().map_or("=", |item| {
+        let line = &lines[item.range.start.line];
+        &line[item.key_range.end.character..item.value_range.start.character]
+    });
+    keys.iter()
+        .filter(|(k, _)| !items.iter().any(|i| model::same_name(k, &i.key)))
+        .map(|(k, v)| format!("{k}{separator}{v}"))
+        .collect()
+}
+
+/// Equal elements, whatever the quotes and the spaces around commas.
+fn same_value(a: &str, b: &str) -> bool {
+    let elements = |text| {
+        model::split(text)
+            .into_iter()
+            .map(|(_, part)| unquote(part.trim()).1)
+            .collect::<Vec<_>>()
+    };
+    elements(a) == elements(b)
+}
+
+/// Strings that would read back as a list, a number or a boolean, or lose spaces, get
+/// double quotes. BNI has no line breaks in values.
+fn quote(text: &str) -> Result<String> {
+    if text.contains(['\n', '\r']) {
+        return Err(ser::Error::custom(
+            "line breaks can't be written in BNI values",
+        ));
+    }
+    let numeric = text.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'))
+        && text.parse::<f64>().is_ok();
+    let typed = numeric || text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false");
+    let plain = !typed && !text.contains(',') && !text.starts_with('"') && text.trim() == text;
+    Ok(match plain {
+        true => text.to_string(),
+        false => format!("\"{text}\""),
+    })
+}
+
+fn unsupported<T>(what: &str, level: Level) -> Result<T> {
+    let place = match level {
+        Level::Key => "a key or section name",
+        Level::Document => "the top level, which holds keys and sections",
+        Level::Section => "a key of a section",
+        Level::Element => "a list element",
+    };
+    Err(ser::Error::custom(format!(
+        "{what} can't be written as {place}"
+    )))
+}
+
+/// Serializes the document, a map or struct.
+pub struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Data;
+    type Error = Error;
+    type SerializeSeq = Impossible<Data, Error>;
+    type SerializeTuple = Impossible<Data, Error>;
+    type SerializeTupleStruct = Impossible<Data, Error>;
+    type SerializeTupleVariant = Impossible<Data, Error>;
+    type SerializeMap = Entries;
+    type SerializeStruct = Entries;
+    type SerializeStructVariant = Impossible<Data, Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<Data> {
+        unsupported("a boolean", Level::Document)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Data> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Data> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Data> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<Data> {
+        unsupported("a number", Level::Document)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Data> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Data> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Data> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<Data> {
+        unsupported("a number", Level::Document)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Data> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<Data> {
+        unsupported("a number", Level::Document)
+    }
+
+    fn serialize_char(self, _: char) -> Result<Data> {
+        unsupported("a string", Level::Document)
+    }
+
+    fn serialize_str(self, _: &str) -> Result<Data> {
+        unsupported("a string", Level::Document)
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Data> {
+        unsupported("bytes", Level::Document)
+    }
+
+    fn serialize_none(self) -> Result<Data> {
+        Ok(Data::default())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Data> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Data> {
+        Ok(Data::default())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Data> {
+        Ok(Data::default())
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> Result<Data> {
+        unsupported("an enum", Level::Document)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Data> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Data> {
+        unsupported("an enum", Level::Document)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq> {
+        unsupported("a list", Level::Document)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple> {
+        unsupported("a tuple", Level::Document)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        unsupported("a tuple", Level::Document)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unsupported("an enum", Level::Document)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Entries> {
+        Ok(Entries::default())
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Entries> {
+        Ok(Entries::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unsupported("an enum", Level::Document)
+    }
+}
+
+/// Keys and sections of the document.
+#[derive(Default)]
+pub struct Entries {
+    data: Data,
+    key: Option<String>,
+}
+
+impl Entries {
+    fn entry<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<()> {
+        match value
+            .serialize(FieldSerializer(Level::Document))
+            .map_err(within(&key))?
+        {
+            Field::Skip => {}
+            Field::Value(value) => self.data.orphans.push((valid_key(key)?, value)),
+            Field::Section(keys) => {
+                if key.contains([']', '\n', '\r']) {
+                    return Err(ser::Error::custom(format!("invalid section name `{key}`")));
+                }
+                self.data.sections.push((key, keys));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for Entries {
+    type Ok = Data;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(name(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self.key.take().unwrap_or_default();
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Result<Data> {
+        Ok(self.data)
+    }
+}
+
+impl ser::SerializeStruct for Entries {
+    type Ok = Data;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.entry(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Data> {
+        Ok(self.data)
+    }
+}
+
+/// Where a value is written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Level {
+    Key,
+    Document,
+    Section,
+    Element,
+}
+
+/// What a value turned into.
+enum Field {
+    /// `None`, the key is left out.
+    Skip,
+    Value(String),
+    Section(Keys),
+}
+
+/// Puts the key in front of errors of its value, `Units`: `Name`: ...
+fn within(key: &str) -> impl Fn(Error) -> Error + '_ {
+    move |err| ser::Error::custom(format!("`{key}`: {}", err.message()))
+}
+
+/// Keys can't be empty, hold `=` or line breaks, or start like a header.
+fn valid_key(key: String) -> Result<String> {
+    match key.is_empty() || key.contains(['=', '\n', '\r']) || key.starts_with('[') {
+        true => Err(ser::Error::custom(format!("invalid key `{key}`"))),
+        false => Ok(key),
+    }
+}
+
+/// Map key or struct field name as written.
+fn name<T: Serialize + ?Sized>(key: &T) -> Result<String> {
+    match key.serialize(FieldSerializer(Level::Key))? {
+        Field::Value(name) => Ok(name),
+        _ => unsupported("nothing", Level::Key),
+    }
+}
+
+/// Serializes a value of the given level.
+struct FieldSerializer(Level);
+
+impl FieldSerializer {
+    fn scalar(self, text: String) -> Result<Field> {
+        Ok(Field::Value(text))
+    }
+}
+
+impl ser::Serializer for FieldSerializer {
+    type Ok = Field;
+    type Error = Error;
+    type SerializeSeq = List;
+    type SerializeTuple = List;
+    type SerializeTupleStruct = List;
+    type SerializeTupleVariant = Impossible<Field, Error>;
+    type SerializeMap = SectionKeys;
+    type SerializeStruct = SectionKeys;
+    type SerializeStructVariant = Impossible<Field, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Field> {
+        self.scalar(v.to_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Field> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Field> {
+        match self.0 {
+            Level::Key => self.scalar(v.to_string()),
+            _ => self.scalar(quote(v)?),
+        }
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Field> {
+        match std::str::from_utf8(v) {
+            Ok(text) => self.serialize_str(text),
+            Err(_) => unsupported("bytes that aren't UTF-8", self.0),
+        }
+    }
+
+    fn serialize_none(self) -> Result<Field> {
+        match self.0 {
+            Level::Element => self.scalar(String::new()),
+            _ => Ok(Field::Skip),
+        }
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Field> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Field> {
+        self.scalar(String::new())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Field> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Field> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Field> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Field> {
+        unsupported("an enum with data", self.0)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<List> {
+        match self.0 {
+            Level::Document | Level::Section => Ok(List::default()),
+            level => unsupported("a list", level),
+        }
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<List> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<List> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        unsupported("an enum with data", self.0)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<SectionKeys> {
+        match self.0 {
+            Level::Document => Ok(SectionKeys::default()),
+            level => unsupported("a map", level),
+        }
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<SectionKeys> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        unsupported("an enum with data", self.0)
+    }
+}
+
+/// Elements of a comma list.
+#[derive(Default)]
+struct List(Vec<String>);
+
+impl List {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        if let Field::Value(text) = value.serialize(FieldSerializer(Level::Element))? {
+            self.0.push(text);
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for List {
+    type Ok = Field;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Field> {
+        Ok(Field::Value(self.0.join(",")))
+    }
+}
+
+impl ser::SerializeTuple for List {
+    type Ok = Field;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Field> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for List {
+    type Ok = Field;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Field> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Keys of one section.
+#[derive(Default)]
+struct SectionKeys {
+    keys: Keys,
+    key: Option<String>,
+}
+
+impl SectionKeys {
+    fn entry<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<()> {
+        let field = value
+            .serialize(FieldSerializer(Level::Section))
+            .map_err(within(&key))?;
+        if let Field::Value(value) = field {
+            self.keys.push((valid_key(key)?, value));
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for SectionKeys {
+    type Ok = Field;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.key = Some(name(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        let key = self.key.take().unwrap_or_default();
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Result<Field> {
+        Ok(Field::Section(self.keys))
+    }
+}
+
+impl ser::SerializeStruct for SectionKeys {
+    type Ok = Field;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.entry(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Field> {
+        Ok(Field::Section(self.keys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lng::bni::de::from_str;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Unit {
+        #[serde(rename = "Name")]
+        name: String,
+        #[serde(rename = "Buttonpos")]
+        buttonpos: Vec<u32>,
+        #[serde(rename = "Tip", skip_serializing_if = "Option::is_none")]
+        tip: Option<String>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct File {
+        version: u32,
+        hfoo: Unit,
+    }
+
+    fn file() -> File {
+        File {
+            version: 2,
+            hfoo: Unit {
+                name: "Footman, Elite".to_string(),
+                buttonpos: vec![0, 2],
+                tip: None,
+            },
+        }
+    }
+
+    #[test]
+    fn writes_sections_and_comma_lists() {
+        let text = to_string(&file()).unwrap();
+        assert_eq!(
+            text,
+            "version=2\n\n[hfoo]\nName=\"Footman, Elite\"\nButtonpos=0,2\n"
+        );
+        assert_eq!(from_str::<File>(&text).unwrap(), file());
+    }
+
+    #[test]
+    fn keeps_comments_and_order() {
+        let original = "; header\nversion = 1\n\n[hfoo]\n// position\nButtonpos = 0, 2\nName = Footman\nTip = old\n";
+        let text = to_string_preserving(original, &file()).unwrap();
+        assert_eq!(
+            text,
+            "; header\nversion = 2\n\n[hfoo]\n// position\nButtonpos = 0, 2\nName = \"Footman, Elite\"\n"
+        );
+        assert_eq!(from_str::<File>(&text).unwrap(), file());
+    }
+
+    #[test]
+    fn adds_keys_once_across_duplicate_sections() {
+        let original =
+            "version=2\n[hfoo]\nName=\"Footman, Elite\"\n[hpea]\n[hfoo]\nButtonpos=1,1\n";
+        let text = to_string_preserving(original, &file()).unwrap();
+        assert_eq!(
+            text,
+            "version=2\n[hfoo]\nName=\"Footman, Elite\"\n[hfoo]\nButtonpos=0,2\n"
+        );
+    }
+
+    #[test]
+    fn rejects_line_breaks() {
+        let mut file = file();
+        file.hfoo.name = "a\nb".to_string();
+        let err = to_string(&file).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "`hfoo`: `Name`: line breaks can't be written in BNI values"
+        );
+    }
+}
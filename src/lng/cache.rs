@@ -0,0 +1,245 @@
+use crate::lng::symbol::Symbol;
+use crate::lsp::range::Range;
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Cache file above this is trimmed, least recently used entries go first.
+pub const MAX_CACHE_SIZE: usize = 32 * 1024 * 1024;
+
+pub static CACHE: Lazy<Mutex<Cache>> = Lazy::new(|| Mutex::new(Cache::load()));
+
+/// Symbols of already seen file contents, so a restart doesn't parse the workspace again.
+/// Keyed by content hash, a changed file simply misses.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cache {
+    entries: HashMap<String, CacheEntry>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub lng: String,
+    pub symbols: Vec<Symbol>,
+    /// See `FileIndex::utf16`, entries of older builds lack it and miss.
+    #[serde(default)]
+    pub utf16: Vec<Range>,
+    /// Unix seconds of the last hit.
+    used: u64,
+}
+
+/// `<cache dir>/jass-tree-sitter-rust`, per platform convention.
+fn root() -> Option<PathBuf> {
+    let base = if cfg!(windows) {
+        env::var_os("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Caches"))
+    } else {
+        env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    };
+    Some(base?.join("jass-tree-sitter-rust"))
+}
+
+/// Symbols of another server version may be computed differently.
+fn file() -> Option<PathBuf> {
+    Some(root()?.join(env!("CARGO_PKG_VERSION")).join("index.json"))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// FNV-1a, stable across Rust releases unlike `DefaultHasher`.
+pub fn hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}-{}", bytes.len())
+}
+
+/// Removes caches of every server version, for `--clear-cache`.
+pub fn clear() {
+    let Some(root) = root() else {
+        return;
+    };
+    match fs::remove_dir_all(&root) {
+        Ok(()) => info!("Cache cleared: {:?}", root),
+        Err(err) => warn!("Can't clear cache {:?}: {}", root, err),
+    }
+}
+
+impl Cache {
+    /// Reads cache of this version, dropping leftovers of the others.
+    fn load() -> Self {
+        let (Some(root), Some(file)) = (root(), file()) else {
+            return Self::default();
+        };
+
+        if let Ok(read) = fs::read_dir(&root) {
+            for entry in read.flatten() {
+                if entry.file_name() != env!("CARGO_PKG_VERSION") {
+                    let _ = fs::remove_dir_all(entry.path());
+                }
+            }
+        }
+
+        let Ok(bytes) = fs::read(&file) else {
+            return Self::default();
+        };
+        match serde_json::from_slice::<Cache>(&bytes) {
+            Ok(cache) => {
+                info!("Cache loaded: {} entries", cache.entries.len());
+                cache
+            }
+            Err(err) => {
+                warn!("Cache {:?} is broken: {}", file, err);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn get(&mut self, hash: &str) -> Option<CacheEntry> {
+        // Access time alone is not worth a write, it goes out with the next change.
+        let entry = self.entries.get_mut(hash)?;
+        entry.used = now();
+        Some(entry.clone())
+    }
+
+    pub fn insert(&mut self, hash: String, lng: &str, symbols: Vec<Symbol>, utf16: Vec<Range>) {
+        self.entries.insert(
+            hash,
+            CacheEntry {
+                lng: lng.to_string(),
+                symbols,
+                utf16,
+                used: now(),
+            },
+        );
+        self.dirty = true;
+    }
+
+    /// Writes the cache if anything changed, trimmed to `MAX_CACHE_SIZE`.
+    pub fn save(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        if let Some(file) = file() {
+            self.write(&file, MAX_CACHE_SIZE);
+        }
+    }
+
+    /// Drops least recently used entries until the JSON fits in `limit` bytes, then
+    /// replaces `file` at once, a server stopped halfway leaves the old cache whole.
+    fn write(&mut self, file: &Path, limit: usize) {
+        let mut json = serde_json::to_vec(self).unwrap();
+        if json.len() > limit {
+            let mut oldest: Vec<(u64, String)> = self
+                .entries
+                .iter()
+                .map(|(hash, e)| (e.used, hash.clone()))
+                .collect();
+            oldest.sort_unstable();
+            let mut size = json.len();
+            for (_, hash) in oldest {
+                if size <= limit {
+                    break;
+                }
+                let entry = self.entries.remove(&hash).unwrap();
+                // `"hash":{..}` without the comma, so `size` never falls below the real one.
+                size -= hash.len() + 3 + serde_json::to_vec(&entry).unwrap().len();
+            }
+            json = serde_json::to_vec(self).unwrap();
+        }
+
+        // Another server on the same workspace may save at the same time.
+        let temp = file.with_extension(format!("json.{}.tmp", process::id()));
+        let written = file
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&temp, &json))
+            .and_then(|_| fs::rename(&temp, file));
+        match written {
+            Ok(()) => self.dirty = false,
+            Err(err) => {
+                let _ = fs::remove_file(&temp);
+                warn!("Can't write cache {:?}: {}", file, err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::position::Position;
+    use crate::lsp::symbol::SymbolKind;
+
+    const START: Range = Range {
+        start: Position {
+            line: 0,
+            character: 0,
+        },
+        end: Position {
+            line: 0,
+            character: 0,
+        },
+    };
+
+    fn symbols(name: &str) -> Vec<Symbol> {
+        vec![Symbol {
+            name: name.into(),
+            kind: SymbolKind::Namespace,
+            range: START,
+            selection_range: START,
+            container: None,
+        }]
+    }
+
+    #[test]
+    fn finds_entries_by_content_hash() {
+        let mut cache = Cache::default();
+        cache.insert(hash(b"[hfoo]\n"), "bni", symbols("hfoo"), vec![START]);
+
+        let entry = cache.get(&hash(b"[hfoo]\n")).unwrap();
+        assert_eq!(entry.lng, "bni");
+        assert_eq!(entry.symbols[0].name, "hfoo");
+        assert!(cache.get(&hash(b"[hbar]\n")).is_none());
+        // Same length, other bytes.
+        assert_ne!(hash(b"ab"), hash(b"ba"));
+    }
+
+    #[test]
+    fn trims_least_recently_used_entries_to_limit() {
+        let dir = env::temp_dir().join(format!("jass-cache-{}", process::id()));
+        let file = dir.join("index.json");
+        let mut cache = Cache::default();
+        for (used, name) in [(3, "c"), (1, "a"), (2, "b")] {
+            cache.insert(name.into(), "bni", symbols(&name.repeat(1000)), Vec::new());
+            cache.entries.get_mut(name).unwrap().used = used;
+        }
+        let full = serde_json::to_vec(&cache).unwrap().len();
+
+        // Room for two entries, the one used longest ago goes.
+        cache.write(&file, full - 500);
+        let written: Cache = serde_json::from_slice(&fs::read(&file).unwrap()).unwrap();
+        let mut kept: Vec<_> = written.entries.keys().cloned().collect();
+        kept.sort();
+        assert_eq!(kept, ["b", "c"]);
+        assert!(!cache.dirty);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
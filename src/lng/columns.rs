@@ -0,0 +1,218 @@
+use crate::lng::symbol::Occurrence;
+use crate::lsp::code_action::{CodeAction, CodeActionParams};
+use crate::lsp::diagnostic::Diagnostic;
+use crate::lsp::hover::Hover;
+use crate::lsp::position::{Position, PositionEncoding};
+use crate::lsp::range::Range;
+use crate::lsp::selection::SelectionRange;
+use crate::lsp::symbol::DocumentSymbol;
+use crate::lsp::text_document::TextEdit;
+use crate::lsp::workspace::WorkspaceEdit;
+use crate::util::line_list::LineList;
+use crate::util::lock::Lock;
+use crate::util::uri_map::URI_MAP;
+use url::Url;
+
+/// Values with positions in document `uri`. Positions they hold in other documents are
+/// left alone, whoever made them converts those with their own text.
+pub trait Positions {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position));
+}
+
+/// Byte columns of one document against the client's unit, what handlers see against
+/// what goes over the wire.
+pub struct Columns<'a> {
+    pub uri: &'a Url,
+    pub line_list: &'a LineList,
+    pub encoding: PositionEncoding,
+}
+
+impl Columns<'_> {
+    /// Positions of the client's unit to byte columns.
+    pub fn incoming<T: Positions>(&self, mut value: T) -> T {
+        if self.encoding != PositionEncoding::Utf8 {
+            value.positions(self.uri, &mut |p| {
+                *p = self.line_list.from_client(*p, self.encoding)
+            });
+        }
+        value
+    }
+
+    /// Byte columns to positions of the client's unit.
+    pub fn outgoing<T: Positions>(&self, mut value: T) -> T {
+        if self.encoding != PositionEncoding::Utf8 {
+            value.positions(self.uri, &mut |p| {
+                *p = self.line_list.to_client(*p, self.encoding)
+            });
+        }
+        value
+    }
+}
+
+/// Runs `f` with the columns of the open document, `None` if it isn't open.
+pub fn open<T>(uri: &Url, encoding: PositionEncoding, f: impl FnOnce(&Columns) -> T) -> Option<T> {
+    let map = URI_MAP.locked();
+    let line_list = map.line_list.get(uri)?;
+    Some(f(&Columns {
+        uri,
+        line_list,
+        encoding,
+    }))
+}
+
+impl Positions for Position {
+    fn positions(&mut self, _: &Url, f: &mut dyn FnMut(&mut Position)) {
+        f(self);
+    }
+}
+
+impl Positions for Range {
+    fn positions(&mut self, _: &Url, f: &mut dyn FnMut(&mut Position)) {
+        f(&mut self.start);
+        f(&mut self.end);
+    }
+}
+
+impl<T: Positions> Positions for Option<T> {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        if let Some(value) = self {
+            value.positions(uri, f);
+        }
+    }
+}
+
+impl<T: Positions> Positions for Vec<T> {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        for value in self {
+            value.positions(uri, f);
+        }
+    }
+}
+
+impl<T: Positions> Positions for Box<T> {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        (**self).positions(uri, f);
+    }
+}
+
+impl Positions for TextEdit {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+    }
+}
+
+impl Positions for Diagnostic {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+        for related in self.related_information.iter_mut().flatten() {
+            if &related.location.uri == uri {
+                related.location.range.positions(uri, f);
+            }
+        }
+    }
+}
+
+impl Positions for Hover {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+    }
+}
+
+impl Positions for SelectionRange {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+        self.parent.positions(uri, f);
+    }
+}
+
+impl Positions for DocumentSymbol {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+        self.selection_range.positions(uri, f);
+        self.children.positions(uri, f);
+    }
+}
+
+impl Positions for WorkspaceEdit {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        if let Some(edits) = self.changes.get_mut(uri) {
+            edits.positions(uri, f);
+        }
+    }
+}
+
+impl Positions for CodeAction {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.diagnostics.positions(uri, f);
+        self.edit.positions(uri, f);
+    }
+}
+
+impl Positions for Occurrence {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+    }
+}
+
+impl Positions for CodeActionParams {
+    fn positions(&mut self, uri: &Url, f: &mut dyn FnMut(&mut Position)) {
+        self.range.positions(uri, f);
+        self.context.diagnostics.positions(uri, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsp::diagnostic::DiagnosticRelatedInformation;
+    use crate::lsp::symbol::Location;
+
+    fn range(line: usize, start: usize, end: usize) -> Range {
+        Range {
+            start: Position {
+                line,
+                character: start,
+            },
+            end: Position {
+                line,
+                character: end,
+            },
+        }
+    }
+
+    #[test]
+    fn converts_positions_of_own_document_only() {
+        let mut line_list = LineList::new();
+        line_list.set_text("[Юнит]\nk='h😀'");
+        let uri = Url::parse("file:///a.bni").unwrap();
+        let other = Url::parse("file:///b.bni").unwrap();
+        let columns = Columns {
+            uri: &uri,
+            line_list: &line_list,
+            encoding: PositionEncoding::Utf16,
+        };
+        let related = |uri: &Url| DiagnosticRelatedInformation {
+            location: Location {
+                uri: uri.clone(),
+                range: range(0, 1, 9),
+            },
+            message: String::new(),
+        };
+        let diagnostic = Diagnostic {
+            range: range(1, 2, 10),
+            severity: None,
+            code: None,
+            source: None,
+            message: String::new(),
+            related_information: Some(vec![related(&uri), related(&other)]),
+        };
+
+        let sent = columns.outgoing(diagnostic);
+        assert_eq!(sent.range, range(1, 2, 8));
+        let related = sent.related_information.as_ref().unwrap();
+        assert_eq!(related[0].location.range, range(0, 1, 5));
+        assert_eq!(related[1].location.range, range(0, 1, 9));
+
+        assert_eq!(columns.incoming(sent).range, range(1, 2, 10));
+    }
+}
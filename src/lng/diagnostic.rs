@@ -0,0 +1,217 @@
+use crate::lng::Backend;
+use crate::lsp::diagnostic::{Diagnostic, DiagnosticSeverity};
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::util::encoding::Encoding;
+use crate::util::line_list::LineList;
+use tree_sitter::{Node, Tree};
+use url::Url;
+
+/// Checks of the backend if it has them, the generic syntax report otherwise.
+pub fn language(
+    backend: &Backend,
+    tree: &Tree,
+    line_list: &LineList,
+    uri: &Url,
+) -> Vec<Diagnostic> {
+    match backend.diagnostics {
+        Some(diagnostics) => diagnostics(tree, line_list, uri),
+        None => syntax(backend, tree, line_list),
+    }
+}
+
+/// Reports ERROR and MISSING nodes of the tree.
+pub fn syntax(backend: &Backend, tree: &Tree, line_list: &LineList) -> Vec<Diagnostic> {
+    let mut result = Vec::new();
+    let mut cursor = tree.walk();
+
+    loop {
+        let node = cursor.node();
+
+        let message = if node.is_missing() {
+            Some(missing_message(&node))
+        } else if node.is_error() {
+            Some(error_message(&node, line_list))
+        } else {
+            None
+        };
+
+        let descend = message.is_none() && node.has_error();
+
+        if let Some(message) = message {
+            result.push(Diagnostic {
+                range: Range::from_node(&node),
+                severity: Some(DiagnosticSeverity::Error),
+                code: None,
+                source: Some(backend.id.to_string()),
+                message,
+                related_information: None,
+            });
+        }
+
+        if descend && cursor.goto_first_child() {
+            continue;
+        }
+
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return result;
+            }
+        }
+    }
+}
+
+/// Warns that a file is not UTF-8, non-ASCII text in it may show wrong in the editor.
+pub fn encoding(encoding: Encoding) -> Option<Diagnostic> {
+    if encoding.is_utf8() {
+        return None;
+    }
+    let start = Position {
+        line: 0,
+        character: 0,
+    };
+    Some(Diagnostic {
+        range: Range { start, end: start },
+        severity: Some(DiagnosticSeverity::Warning),
+        code: None,
+        source: Some("encoding".into()),
+        message: format!("File is not UTF-8, looks like {encoding}"),
+        related_information: None,
+    })
+}
+
+/// `source_file` -> `source file`
+fn humanize(kind: &str) -> String {
+    kind.replace('_', " ")
+}
+
+/// Kind of the closest ancestor that is neither ERROR nor the root.
+fn context(node: &Node) -> Option<String> {
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        p.parent()?;
+        if !p.is_error() {
+            return Some(humanize(p.kind()));
+        }
+        parent = p.parent();
+    }
+    None
+}
+
+fn missing_message(node: &Node) -> String {
+    let what = if node.is_named() {
+        humanize(node.kind())
+    } else {
+        node.kind().to_string()
+    };
+    match context(node) {
+        Some(context) => format!("missing {what} in {context}"),
+        None => format!("missing {what}"),
+    }
+}
+
+fn error_message(node: &Node, line_list: &LineList) -> String {
+    let mut first = *node;
+    while let Some(child) = first.child(0) {
+        first = child;
+    }
+
+    let s = first.start_position();
+    let e = first.end_position();
+    let text = line_list.lines.get(s.row).and_then(|line| {
+        let end = if e.row == s.row { e.column } else { line.len() };
+        line.get(s.column..end)
+    });
+
+    let what = match text.map(str::trim) {
+        Some(text) if !text.is_empty() => {
+            let text: String = text.chars().take(32).collect();
+            format!("unexpected `{text}`")
+        }
+        _ => "syntax error".to_string(),
+    };
+    match context(node) {
+        Some(context) => format!("{what} in {context}"),
+        None => what,
+    }
+}
+
+#[cfg(all(test, feature = "bni"))]
+mod tests {
+    use super::*;
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    /// `(message, line, start, end)` of each syntax error.
+    fn errors(text: &str) -> Vec<(String, usize, usize, usize)> {
+        let document = Document::parse(&BACKEND, text);
+        syntax(&BACKEND, &document.tree, &document.line_list)
+            .into_iter()
+            .inspect(|d| {
+                assert_eq!(d.severity, Some(DiagnosticSeverity::Error));
+                assert_eq!(d.source.as_deref(), Some("bni"));
+            })
+            .map(|d| {
+                let (start, end) = (d.range.start, d.range.end);
+                (d.message, start.line, start.character, end.character)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn accepts_valid_text() {
+        assert!(errors("[hfoo]\nName=Footman\n; done\n").is_empty());
+    }
+
+    #[test]
+    fn quotes_unexpected_text() {
+        assert_eq!(
+            errors("[hfoo]\nName\n"),
+            [("unexpected `Name`".to_string(), 1, 0, 4)]
+        );
+        assert_eq!(
+            errors("[hfoo]]\n"),
+            [("unexpected `]`".to_string(), 0, 6, 7)]
+        );
+    }
+
+    #[test]
+    fn shortens_long_unexpected_text() {
+        let text = format!("[hfoo]]{}\n", "x".repeat(40));
+        assert_eq!(
+            errors(&text),
+            [(format!("unexpected `]{}`", "x".repeat(31)), 0, 6, 47)]
+        );
+    }
+
+    #[test]
+    fn names_missing_token_and_its_node() {
+        let text = format!("]{}\n", "x".repeat(40));
+        assert_eq!(
+            errors(&text),
+            [("missing = in item".to_string(), 0, 41, 41)]
+        );
+    }
+
+    #[test]
+    fn names_enclosing_node_of_error() {
+        // Nothing to quote, the error covers the line break after the key.
+        assert_eq!(
+            errors("]\n="),
+            [("syntax error in item".to_string(), 0, 1, 0)]
+        );
+    }
+
+    #[test]
+    fn warns_about_legacy_encodings_only() {
+        assert!(encoding(Encoding::Utf8).is_none());
+        assert!(encoding(Encoding::Utf8Bom).is_none());
+
+        let warning = encoding(Encoding::Windows1251).unwrap();
+        assert_eq!(warning.severity, Some(DiagnosticSeverity::Warning));
+        assert_eq!(
+            warning.message,
+            "File is not UTF-8, looks like windows-1251"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::lsp::text_document::TextEdit;
+use crate::util::line_list::LineList;
+
+/// Formatted text for a run of whole lines, what formatters of backends return.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineEdit {
+    /// Replaced lines, end exclusive.
+    pub lines: std::ops::Range<usize>,
+    pub text: Vec<String>,
+}
+
+/// Edits the client applies, line breaks of untouched lines stay as they are.
+pub fn text_edits(line_list: &LineList, edits: &[LineEdit]) -> Vec<TextEdit> {
+    let count = line_list.lines.len();
+    let end_of = |row: usize| Position {
+        line: row,
+        character: line_list.lines.get(row).map_or(0, |l| l.len()),
+    };
+
+    edits
+        .iter()
+        .map(|edit| {
+            let start = Position {
+                line: edit.lines.start,
+                character: 0,
+            };
+            if edit.lines.end < count {
+                let end = Position {
+                    line: edit.lines.end,
+                    character: 0,
+                };
+                let new_text = edit.text.iter().map(|l| format!("{l}\n")).collect();
+                return TextEdit {
+                    range: Range { start, end },
+                    new_text,
+                };
+            }
+
+            // Up to the end of the file, whatever line break it ends with stays.
+            let end = end_of(count.saturating_sub(1));
+            if edit.text.is_empty() && edit.lines.start > 0 {
+                return TextEdit {
+                    range: Range {
+                        start: end_of(edit.lines.start - 1),
+                        end,
+                    },
+                    new_text: String::new(),
+                };
+            }
+            TextEdit {
+                range: Range { start, end },
+                new_text: edit.text.join("\n"),
+            }
+        })
+        .collect()
+}
+
+/// Lines of the document with `edits` applied.
+pub fn apply(line_list: &LineList, edits: &[LineEdit]) -> Vec<String> {
+    let mut lines = line_list.lines.clone();
+    let mut edits = edits.to_vec();
+    edits.sort_by_key(|e| e.lines.start);
+    for edit in edits.into_iter().rev() {
+        lines.splice(edit.lines, edit.text);
+    }
+    lines
+}
+
+/// Joins `lines` with the line breaks of `original`: CRLF if it has them, and a final
+/// newline if it ends with one.
+pub fn join(lines: &[String], original: &str) -> String {
+    let eol = if original.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut text = lines.join(eol);
+    if original.ends_with('\n') && !lines.is_empty() {
+        text.push_str(eol);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_list(text: &str) -> LineList {
+        let mut line_list = LineList::new();
+        line_list.set_text(text);
+        line_list
+    }
+
+    #[test]
+    fn replaces_whole_lines() {
+        let line_list = line_list("a\nb\nc");
+        let edits = [LineEdit {
+            lines: 1..2,
+            text: vec!["B".into(), "B2".into()],
+        }];
+        let text_edits = text_edits(&line_list, &edits);
+        assert_eq!(text_edits[0].new_text, "B\nB2\n");
+        assert_eq!(text_edits[0].range.end.line, 2);
+        assert_eq!(apply(&line_list, &edits), ["a", "B", "B2", "c"]);
+    }
+
+    #[test]
+    fn removes_trailing_lines_with_their_break() {
+        let line_list = line_list("a\nb\nc");
+        let edits = [LineEdit {
+            lines: 1..3,
+            text: Vec::new(),
+        }];
+        let edit = &text_edits(&line_list, &edits)[0];
+        assert_eq!((edit.range.start.line, edit.range.start.character), (0, 1));
+        assert_eq!((edit.range.end.line, edit.range.end.character), (2, 1));
+        assert_eq!(edit.new_text, "");
+    }
+
+    #[test]
+    fn joins_with_original_line_breaks() {
+        let lines = ["a".to_string(), "b".to_string()];
+        assert_eq!(join(&lines, "x\r\ny\r\n"), "a\r\nb\r\n");
+        assert_eq!(join(&lines, "x\ny"), "a\nb");
+    }
+}
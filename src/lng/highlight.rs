@@ -0,0 +1,286 @@
+use crate::lng::Backend;
+use crate::lsp::semantic::{TokenModifier, TokenType};
+use crate::lsp::semantic_hub::SemanticTokenHub;
+use crate::util::line_list::LineList;
+use crate::util::lock::Lock;
+use crate::util::uri_map::UriMapEntry;
+use crate::util::workspace::WORKSPACE;
+use log::{error, info};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::sync::{Arc, Mutex};
+use tree_sitter::{Query, QueryCursor, StreamingIterator, Tree};
+
+type QueryMap = HashMap<&'static str, Option<Arc<Query>>>;
+
+static QUERIES: Lazy<Mutex<QueryMap>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Drops compiled queries, so the next highlight picks up workspace overrides again.
+pub fn reset() {
+    QUERIES.locked().clear();
+}
+
+/// `highlights.scm` for backend: `<root>/.jass/queries/<id>/highlights.scm` if present,
+/// the one embedded at compile time otherwise.
+pub fn query(backend: &'static Backend) -> Option<Arc<Query>> {
+    let mut queries = QUERIES.locked();
+    queries
+        .entry(backend.id)
+        .or_insert_with(|| {
+            let language = (backend.language)();
+
+            let path = WORKSPACE
+                .locked()
+                .override_path(&format!("queries/{}/highlights.scm", backend.id));
+
+            if let Some(source) = path.as_ref().and_then(|p| fs::read_to_string(p).ok()) {
+                match Query::new(&language, &source) {
+                    Ok(query) => {
+                        info!("{}: highlights from {:?}", backend.id, path);
+                        return Some(Arc::new(query));
+                    }
+                    Err(err) => error!("{}: invalid highlights {:?}: {}", backend.id, path, err),
+                }
+            }
+
+            match Query::new(&language, backend.highlights) {
+                Ok(query) => Some(Arc::new(query)),
+                Err(err) => {
+                    error!("{}: invalid embedded highlights: {}", backend.id, err);
+                    None
+                }
+            }
+        })
+        .clone()
+}
+
+/// Maps capture name like `function.builtin` to token type and modifiers.
+/// The longest known prefix picks the type, remaining parts add modifiers.
+pub fn capture_token(name: &str) -> Option<(TokenType, Vec<TokenModifier>)> {
+    let parts: Vec<&str> = name.split('.').collect();
+
+    for n in (1..=parts.len()).rev() {
+        let Some((token_type, mut modifiers)) = capture_type(&parts[..n].join(".")) else {
+            continue;
+        };
+        for part in &parts[n..] {
+            if let Some(modifier) = capture_modifier(part)
+                && !modifiers.contains(&modifier)
+            {
+                modifiers.push(modifier);
+            }
+        }
+        return Some((token_type, modifiers));
+    }
+
+    None
+}
+
+fn capture_type(name: &str) -> Option<(TokenType, Vec<TokenModifier>)> {
+    let result = match name {
+        "namespace" | "module" => (TokenType::Namespace, vec![]),
+        "class" => (TokenType::Class, vec![]),
+        "enum" => (TokenType::Enum, vec![]),
+        "interface" => (TokenType::Interface, vec![]),
+        "struct" => (TokenType::Struct, vec![]),
+        "type" => (TokenType::Type, vec![]),
+        "type.parameter" => (TokenType::TypeParameter, vec![]),
+        "variable" => (TokenType::Variable, vec![]),
+        "variable.parameter" | "parameter" => (TokenType::Parameter, vec![]),
+        "variable.member" | "property" | "field" => (TokenType::Property, vec![]),
+        "variable.builtin" => (TokenType::Variable, vec![TokenModifier::DefaultLibrary]),
+        "constant" => (TokenType::Variable, vec![TokenModifier::Readonly]),
+        "constant.builtin" => (
+            TokenType::Variable,
+            vec![TokenModifier::Readonly, TokenModifier::DefaultLibrary],
+        ),
+        "enum.member" | "constant.enum" => (TokenType::EnumMember, vec![]),
+        "attribute" | "decorator" => (TokenType::Decorator, vec![]),
+        "event" => (TokenType::Event, vec![]),
+        "function" | "constructor" => (TokenType::Function, vec![]),
+        "function.method" | "method" => (TokenType::Method, vec![]),
+        "function.macro" | "macro" => (TokenType::Macro, vec![]),
+        "label" => (TokenType::Label, vec![]),
+        "comment" => (TokenType::Comment, vec![]),
+        "comment.documentation" => (TokenType::Comment, vec![TokenModifier::Documentation]),
+        "string" | "character" => (TokenType::String, vec![]),
+        "string.regexp" | "regexp" => (TokenType::Regexp, vec![]),
+        "keyword" | "boolean" => (TokenType::Keyword, vec![]),
+        "number" | "float" => (TokenType::Number, vec![]),
+        "operator" => (TokenType::Operator, vec![]),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn capture_modifier(name: &str) -> Option<TokenModifier> {
+    Some(match name {
+        "declaration" => TokenModifier::Declaration,
+        "definition" => TokenModifier::Definition,
+        "readonly" | "constant" => TokenModifier::Readonly,
+        "static" => TokenModifier::Static,
+        "deprecated" => TokenModifier::Deprecated,
+        "abstract" => TokenModifier::Abstract,
+        "async" => TokenModifier::Async,
+        "modification" => TokenModifier::Modification,
+        "documentation" => TokenModifier::Documentation,
+        "builtin" | "defaultLibrary" => TokenModifier::DefaultLibrary,
+        _ => return None,
+    })
+}
+
+/// Fills semantic tokens of the entry from captures of backend highlights query.
+pub fn highlight(backend: &'static Backend, entry: UriMapEntry) {
+    let Some(tree) = entry.tree.as_ref() else {
+        entry.semantic.clear();
+        return;
+    };
+    tokens(backend, tree, entry.line_list, entry.semantic);
+}
+
+/// Captures come in document order, the one overlapping an already taken range is skipped,
+/// so for the same node the earlier pattern wins.
+pub fn tokens(
+    backend: &'static Backend,
+    tree: &Tree,
+    line_list: &LineList,
+    semantic: &mut SemanticTokenHub,
+) {
+    semantic.clear();
+
+    let Some(query) = query(backend) else {
+        return;
+    };
+
+    let tokens: Vec<Option<(TokenType, Vec<TokenModifier>)>> = query
+        .capture_names()
+        .iter()
+        .map(|name| capture_token(name))
+        .collect();
+
+    let text = line_list.to_text();
+    let mut cursor = QueryCursor::new();
+    let mut captures = cursor.captures(&query, tree.root_node(), text.as_bytes());
+
+    let mut taken = 0;
+
+    while let Some((m, index)) = captures.next() {
+        let capture = m.captures[*index];
+        let Some((token_type, modifiers)) = &tokens[capture.index as usize] else {
+            continue;
+        };
+
+        let node = capture.node;
+        if node.start_byte() == node.end_byte() || node.start_byte() < taken {
+            continue;
+        }
+        taken = node.end_byte();
+
+        let s = node.start_position();
+        let e = node.end_position();
+
+        // Semantic token can't cross a line break.
+        for row in s.row..=e.row {
+            let start = if row == s.row { s.column } else { 0 };
+            let end = if row == e.row {
+                e.column
+            } else {
+                line_list.lines.get(row).map_or(start, |l| l.len())
+            };
+            if end > start {
+                semantic.add(
+                    row,
+                    start,
+                    end - start,
+                    token_type.clone(),
+                    modifiers.clone(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bni"))]
+mod tests {
+    use crate::lng::bni::BACKEND;
+    use crate::lng::Document;
+
+    /// `(line, column, text, type)` of each token, declarations marked with `!`.
+    fn tokens(text: &str) -> Vec<(usize, usize, String, String)> {
+        let document = Document::parse(&BACKEND, text);
+        let semantic = document.semantic();
+        semantic
+            .lines
+            .values()
+            .flat_map(|line| &line.tokens)
+            .map(|t| {
+                let line = &document.line_list.lines[t.line];
+                let mark = match t.modifiers.is_empty() {
+                    true => "",
+                    false => "!",
+                };
+                (
+                    t.line,
+                    t.pos,
+                    line[t.pos..t.pos + t.len].to_string(),
+                    format!("{}{mark}", t.token_type),
+                )
+            })
+            .collect()
+    }
+
+    fn kinds(text: &str) -> Vec<(String, String)> {
+        tokens(text)
+            .into_iter()
+            .map(|(_, _, t, k)| (t, k))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected
+            .iter()
+            .map(|(t, k)| (t.to_string(), k.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn highlights_headers_and_comments() {
+        assert_eq!(
+            kinds("; units\n[hfoo]\n"),
+            pairs(&[
+                ("; units", "Comment"),
+                ("[", "Operator"),
+                ("hfoo", "Namespace!"),
+                ("]", "Operator"),
+            ])
+        );
+    }
+
+    #[test]
+    fn highlights_typed_values() {
+        assert_eq!(
+            kinds("Name = Footman, 1.5, TRUE, 'hfoo', h00A, \"a, b\"\n"),
+            pairs(&[
+                ("Name", "Property!"),
+                ("=", "Operator"),
+                ("Footman", "String"),
+                (",", "Operator"),
+                ("1.5", "Number"),
+                (",", "Operator"),
+                ("TRUE", "Keyword"),
+                (",", "Operator"),
+                ("'hfoo'", "Variable!"),
+                (",", "Operator"),
+                ("h00A", "Variable!"),
+                (",", "Operator"),
+                ("\"a, b\"", "String"),
+            ])
+        );
+    }
+
+    #[test]
+    fn leaves_empty_value_alone() {
+        assert_eq!(tokens("Tip=\n").len(), 2);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::lng::cache::{self, CACHE};
+use crate::lng::symbol::Symbol;
+use crate::lng::{backend_for_path, source_files, Backend, Document};
+use crate::lsp::position::PositionEncoding;
+use crate::lsp::range::Range;
+use crate::util::isolate::isolate;
+use crate::util::line_list::LineList;
+use crate::util::lock::Lock;
+use crate::util::metrics::METRICS;
+use crate::util::progress::Progress;
+use crate::util::uri_map::URI_MAP;
+use crate::util::workspace::WORKSPACE;
+use log::info;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+use tree_sitter::Tree;
+use url::Url;
+
+pub static INDEX: Lazy<Mutex<Index>> = Lazy::new(|| Mutex::new(Index::default()));
+
+/// Symbols of every workspace file, opened or not.
+#[derive(Debug, Default)]
+pub struct Index {
+    pub files: HashMap<Url, FileIndex>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FileIndex {
+    pub lng: &'static str,
+    pub symbols: Vec<Symbol>,
+    /// Selection ranges of `symbols` in UTF-16 units, there's no text to convert them
+    /// with once the file is closed.
+    pub utf16: Vec<Range>,
+}
+
+impl FileIndex {
+    pub fn new(backend: &'static Backend, tree: &Tree, line_list: &LineList) -> Self {
+        let symbols = (backend.symbols)(tree, line_list);
+        let utf16 = symbols
+            .iter()
+            .map(|symbol| Range {
+                start: line_list.to_client(symbol.selection_range.start, PositionEncoding::Utf16),
+                end: line_list.to_client(symbol.selection_range.end, PositionEncoding::Utf16),
+            })
+            .collect();
+        Self {
+            lng: backend.id,
+            symbols,
+            utf16,
+        }
+    }
+}
+
+impl Index {
+    /// Symbols whose name contains all characters of `query` in order, ignoring case,
+    /// the way editors match `Go to Symbol in Workspace`. Each comes with its selection
+    /// range in `encoding`.
+    pub fn search(&self, query: &str, encoding: PositionEncoding) -> Vec<(&Url, &Symbol, Range)> {
+        let query = query.to_lowercase();
+        let mut result = Vec::new();
+
+        for (uri, file) in &self.files {
+            for (symbol, utf16) in file.symbols.iter().zip(&file.utf16) {
+                let mut name = symbol.name.chars().flat_map(char::to_lowercase);
+                if query.chars().all(|q| name.any(|c| c == q)) {
+                    let range = match encoding {
+                        PositionEncoding::Utf8 => symbol.selection_range,
+                        PositionEncoding::Utf16 => *utf16,
+                    };
+                    result.push((uri, symbol, range));
+                }
+            }
+        }
+
+        result.sort_by(|a, b| (a.1.name.len(), a.0).cmp(&(b.1.name.len(), b.0)));
+        result
+    }
+}
+
+/// Indexes the file from disk, unless the editor owns it.
+pub fn update_path(path: &Path) {
+    let Ok(uri) = Url::from_file_path(path) else {
+        return;
+    };
+    if URI_MAP.locked().tree.contains_key(&uri) {
+        return;
+    }
+    let Some(backend) = backend_for_path(path) else {
+        return;
+    };
+    let Ok(bytes) = fs::read(path) else {
+        return store(uri, None);
+    };
+
+    let hash = cache::hash(&bytes);
+    let cached = CACHE.locked().get(&hash);
+
+    let file = match cached {
+        Some(entry) if entry.lng == backend.id && entry.utf16.len() == entry.symbols.len() => {
+            FileIndex {
+                lng: backend.id,
+                symbols: entry.symbols,
+                utf16: entry.utf16,
+            }
+        }
+        _ => {
+            let started = Instant::now();
+            let document = Document::decode(backend, &bytes);
+            METRICS.locked().parse(backend.id, started.elapsed());
+            let file = FileIndex::new(backend, &document.tree, &document.line_list);
+            CACHE
+                .locked()
+                .insert(hash, backend.id, file.symbols.clone(), file.utf16.clone());
+            file
+        }
+    };
+    store(uri, Some(file));
+}
+
+/// Indexes what was read from disk, or drops the file for `None`, unless the editor
+/// opened it meanwhile and its buffer wins. Opening indexes under the `URI_MAP` lock,
+/// so holding it settles which comes last.
+fn store(uri: Url, file: Option<FileIndex>) {
+    let map = URI_MAP.locked();
+    if map.tree.contains_key(&uri) {
+        return;
+    }
+    let mut index = INDEX.locked();
+    match file {
+        Some(file) => index.files.insert(uri, file),
+        None => index.files.remove(&uri),
+    };
+}
+
+/// Keeps the index in sync with the editor buffer.
+pub fn update_open(uri: &Url, backend: &'static Backend, tree: &Tree, line_list: &LineList) {
+    let file = FileIndex::new(backend, tree, line_list);
+    INDEX.locked().files.insert(uri.clone(), file);
+}
+
+pub fn remove(uri: &Url) {
+    INDEX.locked().files.remove(uri);
+}
+
+/// Indexes every file of known languages under `folders`.
+/// `progress` gets `(done, total)` before each file and stops the scan by returning `false`.
+pub fn scan(folders: &[PathBuf], mut progress: impl FnMut(usize, usize) -> bool) {
+    let started = Instant::now();
+    let files: Vec<PathBuf> = folders.iter().flat_map(|f| source_files(f)).collect();
+    let total = files.len();
+
+    for (done, path) in files.iter().enumerate() {
+        if !progress(done, total) {
+            CACHE.locked().save();
+            info!("Indexing cancelled after {done} of {total} files");
+            return;
+        }
+        update_path(path);
+    }
+    progress(total, total);
+
+    CACHE.locked().save();
+    info!("Indexed {} files in {:?}", total, started.elapsed());
+}
+
+/// Scans workspace folders in background, reporting work-done progress.
+pub fn spawn_scan() {
+    let folders = WORKSPACE.locked().folders.clone();
+    if folders.is_empty() {
+        return;
+    }
+
+    thread::spawn(move || {
+        let _ = isolate(|| {
+            let mut progress = Progress::begin("Indexing workspace");
+            scan(&folders, |done, total| {
+                progress.report(done, total);
+                !progress.is_cancelled()
+            });
+        });
+    });
+}
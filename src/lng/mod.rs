@@ -1 +1,217 @@
-pub mod bni;
\ No newline at end of file
+#[cfg(feature = "bni")]
+pub mod bni;
+#[cfg(feature = "server")]
+pub mod cache;
+#[cfg(feature = "server")]
+pub mod columns;
+pub mod diagnostic;
+pub mod format;
+pub mod highlight;
+#[cfg(feature = "server")]
+pub mod index;
+#[cfg(feature = "server")]
+pub mod publish;
+#[cfg(feature = "server")]
+pub mod reference;
+#[cfg(feature = "server")]
+mod session;
+pub mod symbol;
+
+#[cfg(feature = "server")]
+pub use session::{change, mark_stale, open};
+
+use crate::lng::format::LineEdit;
+use crate::lng::symbol::{Occurrence, Symbol};
+use crate::lsp::code_action::CodeAction;
+use crate::lsp::completion::CompletionItem;
+use crate::lsp::diagnostic::Diagnostic;
+use crate::lsp::folding::FoldingRange;
+use crate::lsp::hover::Hover;
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::lsp::selection::SelectionRange;
+use crate::lsp::semantic_hub::SemanticTokenHub;
+use crate::util::encoding::{self, Encoding};
+use crate::util::line_list::LineList;
+use crate::util::settings::{FormatSettings, Settings};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Language, Parser, Tree};
+use url::Url;
+
+/// Language checks of a document, see `Backend::diagnostics`.
+pub type Checks = fn(&Tree, &LineList, &Url) -> Vec<Diagnostic>;
+
+/// Selection chains of a document at the given positions, see `Backend::selection`.
+pub type Selections = fn(&Tree, &LineList, &[Position]) -> Vec<SelectionRange>;
+
+/// Formatting of a whole document, or of what `Range` touches, see `Backend::format`.
+pub type Formatter = fn(&Tree, &LineList, &FormatSettings, Option<Range>) -> Vec<LineEdit>;
+
+/// Hover at a position of a document, see `Backend::hover`.
+pub type Docs = fn(&Tree, &LineList, &Url, Position) -> Option<Hover>;
+
+/// Completion at a position of a document, see `Backend::completion`.
+pub type Completions = fn(&Tree, &LineList, &Url, Position) -> Vec<CompletionItem>;
+
+/// Declarations and uses of names other files can refer to, see `Backend::references`.
+pub type References = fn(&Tree, &LineList, &Settings) -> Vec<Occurrence>;
+
+/// Quick fixes and refactors for a range of a document, see `Backend::actions`.
+pub type Actions = fn(&Tree, &LineList, &Url, Range, &[Diagnostic]) -> Vec<CodeAction>;
+
+/// Everything the server needs to know about a language: its grammar and bundled queries.
+pub struct Backend {
+    /// `languageId` from the client.
+    pub id: &'static str,
+    /// File extensions without the dot.
+    pub extensions: &'static [&'static str],
+    pub language: fn() -> Language,
+    /// `highlights.scm` embedded at compile time.
+    pub highlights: &'static str,
+    /// Declarations for outline and workspace index.
+    pub symbols: fn(&Tree, &LineList) -> Vec<Symbol>,
+    /// Language checks replacing `diagnostic::syntax`, `uri` is for related information.
+    pub diagnostics: Option<Checks>,
+    /// Folds, without them the client folds by indentation.
+    pub folding: Option<fn(&Tree, &LineList) -> Vec<FoldingRange>>,
+    /// Expanding selections, one chain per position.
+    pub selection: Option<Selections>,
+    /// Document or range formatting, as replaced lines.
+    pub format: Option<Formatter>,
+    /// Documentation of what is under the cursor.
+    pub hover: Option<Docs>,
+    /// Suggestions at the cursor.
+    pub completion: Option<Completions>,
+    /// Names for definition, references and rename across workspace files.
+    pub references: Option<References>,
+    /// Why a name can't be the new one of a rename, `None` if it can. Rename is offered
+    /// with `references` and this.
+    pub rename: Option<fn(&str) -> Option<String>>,
+    /// Code actions over a range, `Diagnostic`s being the ones the client shows there.
+    pub actions: Option<Actions>,
+}
+
+/// Drops what was read from `.jass` overrides, so it is read again on next use.
+pub fn reset() {
+    highlight::reset();
+    #[cfg(feature = "bni")]
+    bni::schema::reset();
+}
+
+/// Backends compiled in, one cargo feature each.
+pub static BACKENDS: &[&Backend] = &[
+    #[cfg(feature = "bni")]
+    &bni::BACKEND,
+];
+
+pub fn backend(id: &str) -> Option<&'static Backend> {
+    BACKENDS.iter().copied().find(|b| b.id == id)
+}
+
+pub fn backend_for_path(path: &Path) -> Option<&'static Backend> {
+    let ext = path.extension()?.to_str()?.to_lowercase();
+    BACKENDS
+        .iter()
+        .copied()
+        .find(|b| b.extensions.contains(&ext.as_str()))
+}
+
+impl Backend {
+    pub fn parser(&self) -> Parser {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&(self.language)())
+            .unwrap_or_else(|_| panic!("Error loading {} parser", self.id));
+        parser
+    }
+}
+
+/// Files of known languages under `dir`, hidden directories skipped.
+pub fn source_files(dir: &Path) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    walk(dir, &mut result);
+    result
+}
+
+fn walk(dir: &Path, result: &mut Vec<PathBuf>) {
+    let Ok(read) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut entries: Vec<PathBuf> = read.filter_map(|e| Some(e.ok()?.path())).collect();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            let hidden = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.'));
+            if !hidden {
+                walk(&path, result);
+            }
+        } else if backend_for_path(&path).is_some() {
+            result.push(path);
+        }
+    }
+}
+
+/// File parsed outside of the editor session, for the CLI and library users.
+pub struct Document {
+    pub backend: &'static Backend,
+    pub line_list: LineList,
+    pub tree: Tree,
+    pub encoding: Encoding,
+}
+
+impl Document {
+    pub fn parse(backend: &'static Backend, text: &str) -> Self {
+        let mut line_list = LineList::new();
+        line_list.set_text(text);
+        let tree = backend.parser().parse(text, None).unwrap();
+        Self {
+            backend,
+            line_list,
+            tree,
+            encoding: Encoding::Utf8,
+        }
+    }
+
+    /// File contents as read from disk, in whatever encoding they are.
+    pub fn decode(backend: &'static Backend, bytes: &[u8]) -> Self {
+        let decoded = encoding::decode(bytes);
+        Self {
+            encoding: decoded.encoding,
+            ..Self::parse(backend, &decoded.text)
+        }
+    }
+
+    /// `uri` is where related information points, the file this was read from.
+    pub fn diagnostics(&self, uri: &Url) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::from_iter(diagnostic::encoding(self.encoding));
+        diagnostics.extend(diagnostic::language(
+            self.backend,
+            &self.tree,
+            &self.line_list,
+            uri,
+        ));
+        diagnostics
+    }
+
+    /// Lines after formatting, `None` if the language has no formatter.
+    pub fn format(&self, settings: &FormatSettings) -> Option<Vec<String>> {
+        let format = self.backend.format?;
+        let edits = format(&self.tree, &self.line_list, settings, None);
+        Some(format::apply(&self.line_list, &edits))
+    }
+
+    pub fn symbols(&self) -> Vec<Symbol> {
+        (self.backend.symbols)(&self.tree, &self.line_list)
+    }
+
+    pub fn semantic(&self) -> SemanticTokenHub {
+        let mut semantic = SemanticTokenHub::new();
+        highlight::tokens(self.backend, &self.tree, &self.line_list, &mut semantic);
+        semantic
+    }
+}
@@ -0,0 +1,152 @@
+use crate::lng::columns::Columns;
+use crate::lng::{backend, backend_for_path, diagnostic, source_files, Document};
+use crate::lsp::diagnostic::{Diagnostic, DiagnosticSeverity, PublishDiagnosticsParams};
+use crate::lsp::rpc::notify;
+use crate::util::client::CLIENT;
+use crate::util::debounce::Debouncer;
+use crate::util::lock::Lock;
+use crate::util::progress::Progress;
+use crate::util::uri_map::URI_MAP;
+use crate::util::workspace::WORKSPACE;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use url::Url;
+
+static DEBOUNCE: Lazy<Debouncer<Url>> =
+    Lazy::new(|| Debouncer::new(Duration::from_millis(300), publish));
+
+/// Closed files whose diagnostics were pushed to a client that pulls the opened ones.
+static PUSHED: Lazy<Mutex<HashSet<Url>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// `workspace/executeCommand` running `check_workspace`.
+pub const CHECK_WORKSPACE: &str = "jass.checkWorkspace";
+
+/// Totals of a full-project check, the command result.
+#[derive(Debug, Default, Serialize)]
+pub struct WorkspaceCheck {
+    pub files: usize,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+/// Recomputes diagnostics of the opened document and keeps them in `URI_MAP`, returns
+/// them in the client's unit.
+pub fn refresh(uri: &Url) -> Vec<Diagnostic> {
+    let encoding = CLIENT.locked().position_encoding;
+    let mut map = URI_MAP.locked();
+    let entry = map.entry(uri);
+
+    let mut diagnostics = Vec::from_iter(diagnostic::encoding(*entry.encoding));
+    if let (Some(backend), Some(tree)) =
+        (entry.lng.as_deref().and_then(backend), entry.tree.as_ref())
+    {
+        diagnostics.extend(diagnostic::language(backend, tree, entry.line_list, uri));
+    }
+
+    *entry.diagnostics = diagnostics.clone();
+    Columns {
+        uri,
+        line_list: entry.line_list,
+        encoding,
+    }
+    .outgoing(diagnostics)
+}
+
+fn send(uri: &Url, diagnostics: Vec<Diagnostic>) {
+    if CLIENT.locked().pull_diagnostics {
+        let open = URI_MAP.locked().tree.contains_key(uri);
+        let mut pushed = PUSHED.locked();
+        if open {
+            // The client pulls these itself, drop what a workspace check pushed.
+            if !pushed.remove(uri) {
+                return;
+            }
+            drop(pushed);
+            return push(uri, Vec::new());
+        }
+        if diagnostics.is_empty() {
+            pushed.remove(uri);
+        } else {
+            pushed.insert(uri.clone());
+        }
+    }
+    push(uri, diagnostics);
+}
+
+fn push(uri: &Url, diagnostics: Vec<Diagnostic>) {
+    notify(
+        "textDocument/publishDiagnostics",
+        PublishDiagnosticsParams {
+            uri: uri.clone(),
+            diagnostics,
+        },
+    );
+}
+
+pub fn publish(uri: &Url) {
+    if !URI_MAP.locked().tree.contains_key(uri) {
+        return;
+    }
+    send(uri, refresh(uri));
+}
+
+/// Publishes after the user stops typing.
+pub fn schedule(uri: &Url) {
+    DEBOUNCE.touch(uri.clone());
+}
+
+pub fn clear(uri: &Url) {
+    send(uri, Vec::new());
+}
+
+/// Publishes diagnostics of every workspace file, opened ones from their buffers.
+/// `None` when cancelled through `progress`.
+pub fn check_workspace(progress: &mut Progress) -> Option<WorkspaceCheck> {
+    let folders = WORKSPACE.locked().folders.clone();
+    let files: Vec<PathBuf> = folders.iter().flat_map(|f| source_files(f)).collect();
+    let encoding = CLIENT.locked().position_encoding;
+    let mut check = WorkspaceCheck::default();
+
+    for (done, path) in files.iter().enumerate() {
+        progress.report(done, files.len());
+        if progress.is_cancelled() {
+            return None;
+        }
+
+        let (Ok(uri), Some(backend)) = (Url::from_file_path(path), backend_for_path(path)) else {
+            continue;
+        };
+        let diagnostics = if URI_MAP.locked().tree.contains_key(&uri) {
+            refresh(&uri)
+        } else {
+            let Ok(bytes) = fs::read(path) else {
+                continue;
+            };
+            let document = Document::decode(backend, &bytes);
+            Columns {
+                uri: &uri,
+                line_list: &document.line_list,
+                encoding,
+            }
+            .outgoing(document.diagnostics(&uri))
+        };
+
+        check.files += 1;
+        for diagnostic in &diagnostics {
+            match diagnostic.severity {
+                Some(DiagnosticSeverity::Error) => check.errors += 1,
+                Some(DiagnosticSeverity::Warning) => check.warnings += 1,
+                _ => {}
+            }
+        }
+        send(&uri, diagnostics);
+    }
+    progress.report(files.len(), files.len());
+
+    Some(check)
+}
@@ -0,0 +1,122 @@
+use crate::lng::columns::Columns;
+use crate::lng::index::INDEX;
+use crate::lng::symbol::Occurrence;
+use crate::lng::{backend, Backend, Document, References};
+use crate::lsp::position::{Position, PositionEncoding};
+use crate::lsp::range::Range;
+use crate::util::line_list::LineList;
+use crate::util::lock::Lock;
+use crate::util::settings::Settings;
+use crate::util::uri_map::URI_MAP;
+use std::collections::BTreeSet;
+use std::fs;
+use tree_sitter::Tree;
+use url::Url;
+
+/// Name under the cursor of an open document, with the backend of the document.
+pub fn at(
+    uri: &Url,
+    position: Position,
+    settings: &Settings,
+) -> Option<(&'static Backend, Occurrence)> {
+    let map = URI_MAP.locked();
+    let backend = map
+        .lng
+        .get(uri)
+        .and_then(Option::as_deref)
+        .and_then(backend)?;
+    let references = backend.references?;
+    let tree = map.tree.get(uri)?.as_ref()?;
+    let line_list = map.line_list.get(uri)?;
+
+    let cursor = Range {
+        start: position,
+        end: position,
+    };
+    references(tree, line_list, settings)
+        .into_iter()
+        .find(|o| o.range.contains(&cursor))
+        .map(|o| (backend, o))
+}
+
+/// Declarations and uses of `key` in workspace files of `backend`, open ones from their
+/// buffers, the rest from disk, ordered by file and position. Nothing when no file
+/// declares it, so values that only look like names don't count. Ranges are in
+/// `encoding`, converted with the text of their own file.
+pub fn find(
+    backend: &'static Backend,
+    key: &str,
+    settings: &Settings,
+    encoding: PositionEncoding,
+) -> Vec<(Url, Occurrence)> {
+    let Some(references) = backend.references else {
+        return Vec::new();
+    };
+
+    let mut uris: BTreeSet<Url> = INDEX
+        .locked()
+        .files
+        .iter()
+        .filter(|(_, file)| file.lng == backend.id)
+        .map(|(uri, _)| uri.clone())
+        .collect();
+    uris.extend(
+        URI_MAP
+            .locked()
+            .lng
+            .iter()
+            .filter(|(_, lng)| lng.as_deref() == Some(backend.id))
+            .map(|(uri, _)| uri.clone()),
+    );
+
+    let mut result = Vec::new();
+    for uri in uris {
+        let mut found = occurrences(&uri, backend, references, settings, encoding, key);
+        found.sort_by_key(|o| (o.range.start.line, o.range.start.character));
+        result.extend(found.into_iter().map(|o| (uri.clone(), o)));
+    }
+
+    match result.iter().any(|(_, o)| o.declaration) {
+        true => result,
+        false => Vec::new(),
+    }
+}
+
+/// Occurrences of `key` in one file.
+fn occurrences(
+    uri: &Url,
+    backend: &'static Backend,
+    references: References,
+    settings: &Settings,
+    encoding: PositionEncoding,
+    key: &str,
+) -> Vec<Occurrence> {
+    let matching = |tree: &Tree, line_list: &LineList| {
+        let found = references(tree, line_list, settings)
+            .into_iter()
+            .filter(|o| o.key == key)
+            .collect();
+        Columns {
+            uri,
+            line_list,
+            encoding,
+        }
+        .outgoing::<Vec<Occurrence>>(found)
+    };
+
+    {
+        let map = URI_MAP.locked();
+        if let (Some(Some(tree)), Some(line_list)) = (map.tree.get(uri), map.line_list.get(uri)) {
+            return matching(tree, line_list);
+        }
+    }
+
+    let Ok(bytes) = uri
+        .to_file_path()
+        .and_then(|path| fs::read(path).map_err(|_| ()))
+    else {
+        return Vec::new();
+    };
+    let document = Document::decode(backend, &bytes);
+    matching(&document.tree, &document.line_list)
+}
@@ -0,0 +1,134 @@
+use crate::lng::{backend, highlight, index, Backend};
+use crate::lsp::rpc::notify;
+use crate::lsp::text_document::TextDocumentContentChangeEvent;
+use crate::lsp::window::{MessageType, ShowMessageParams};
+use crate::util::client::CLIENT;
+use crate::util::encoding::{self, Encoding};
+use crate::util::lock::Lock;
+use crate::util::metrics::METRICS;
+use crate::util::uri_map::URI_MAP;
+use log::{info, warn};
+use std::fs;
+use std::time::Instant;
+use url::Url;
+
+pub fn open(uri: &Url, backend: &'static Backend, text: impl AsRef<[u8]>) {
+    let decoded = encoding::decode(text.as_ref());
+    let text = decoded.text;
+
+    // Client decoded a legacy file as UTF-8 and sent us replacement characters.
+    let encoding = match decoded.encoding {
+        Encoding::Utf8 if text.contains('\u{FFFD}') => disk_encoding(uri).unwrap_or_default(),
+        encoding => encoding,
+    };
+
+    let mut map = URI_MAP.locked();
+    map.stale.remove(uri);
+    let mut entry = map.entry(uri);
+
+    let line_list = &mut entry.line_list;
+    line_list.set_text(&text);
+
+    *entry.encoding = encoding;
+    entry.lng.replace(backend.id.to_string());
+    let started = Instant::now();
+    let tree = backend.parser().parse(&text, None).unwrap();
+    METRICS.locked().parse(backend.id, started.elapsed());
+    index::update_open(uri, backend, &tree, entry.line_list);
+    entry.tree.replace(tree);
+    info!("open");
+
+    highlight::highlight(backend, entry);
+}
+
+pub fn change(uri: &Url, mut changes: Vec<TextDocumentContentChangeEvent>) {
+    let encoding = CLIENT.locked().position_encoding;
+    let mut map = URI_MAP.locked();
+
+    // Everything before the last full text is overwritten by it.
+    let full = changes.iter().rposition(|c| c.range.is_none());
+    if let Some(index) = full {
+        changes.drain(..index);
+    }
+
+    // Ranged changes can't be applied to text we don't trust.
+    if map.stale.contains(uri) {
+        if full.is_none() {
+            return;
+        }
+        map.stale.remove(uri);
+    }
+
+    let entry = map.entry(uri);
+
+    let Some(backend) = entry.lng.as_deref().and_then(backend) else {
+        return;
+    };
+
+    let line_list = &mut *entry.line_list;
+    let mut tree = entry.tree.take();
+    if tree.is_none() && full.is_none() {
+        warn!("{}: change without a tree", uri);
+        drop(map);
+        return out_of_sync(uri);
+    }
+
+    for change in changes {
+        let new_text = &change.text;
+
+        let Some(range) = &change.range else {
+            line_list.set_text(new_text);
+            continue;
+        };
+
+        let start = line_list.from_client(range.start, encoding);
+        let end = line_list.from_client(range.end, encoding);
+        let Some(edit) = line_list.apply_change(&start, &end, new_text) else {
+            warn!("{}: change {:?} out of range", uri, range);
+            drop(map);
+            return out_of_sync(uri);
+        };
+        if let Some(tree) = &mut tree {
+            tree.edit(&edit);
+        }
+    }
+
+    let old_tree = if full.is_some() { None } else { tree.as_ref() };
+    let new_text = line_list.to_text();
+    let started = Instant::now();
+    let new_tree = backend.parser().parse(&new_text, old_tree).unwrap();
+    METRICS.locked().parse(backend.id, started.elapsed());
+    index::update_open(uri, backend, &new_tree, line_list);
+    entry.tree.replace(new_tree);
+
+    highlight::highlight(backend, entry);
+}
+
+fn disk_encoding(uri: &Url) -> Option<Encoding> {
+    let bytes = fs::read(uri.to_file_path().ok()?).ok()?;
+    Some(encoding::decode(&bytes).encoding)
+}
+
+/// Document can't be trusted anymore, it waits for the client to send it whole.
+pub fn mark_stale(uri: &Url) {
+    URI_MAP.locked().invalidate(uri);
+}
+
+/// Our text of the document and the client's went apart. Clients only send it whole on
+/// open, so the user is asked to reopen it.
+fn out_of_sync(uri: &Url) {
+    mark_stale(uri);
+    let name = uri
+        .path_segments()
+        .and_then(|mut s| s.next_back())
+        .unwrap_or("");
+    notify(
+        "window/showMessage",
+        ShowMessageParams {
+            message_type: MessageType::Warning,
+            message: format!(
+                "{name} went out of sync with the editor, reopen it to get highlighting and diagnostics back"
+            ),
+        },
+    );
+}
@@ -0,0 +1,61 @@
+use crate::lsp::range::Range;
+use crate::lsp::symbol::{DocumentSymbol, SymbolKind};
+use serde::{Deserialize, Serialize};
+
+/// Named thing declared in a file, what the workspace index is made of.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Whole declaration.
+    pub range: Range,
+    /// Just the name.
+    pub selection_range: Range,
+    /// Name of the enclosing symbol.
+    pub container: Option<String>,
+}
+
+/// Place a name is declared or used at, what references and rename work with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence {
+    /// Name folded the way the language compares names.
+    pub key: String,
+    /// Just the name.
+    pub range: Range,
+    pub declaration: bool,
+}
+
+/// Symbols of one file nested by range, each under the closest preceding one containing it.
+pub fn outline(symbols: &[Symbol]) -> Vec<DocumentSymbol> {
+    let mut roots = Vec::new();
+    let mut open: Vec<DocumentSymbol> = Vec::new();
+
+    let close = |open: &mut Vec<DocumentSymbol>, roots: &mut Vec<DocumentSymbol>| {
+        let done = open.pop().unwrap();
+        match open.last_mut() {
+            Some(parent) => parent.children.push(done),
+            None => roots.push(done),
+        }
+    };
+
+    for symbol in symbols {
+        while open
+            .last()
+            .is_some_and(|parent| !parent.range.contains(&symbol.range))
+        {
+            close(&mut open, &mut roots);
+        }
+        open.push(DocumentSymbol {
+            name: symbol.name.clone(),
+            kind: symbol.kind,
+            range: symbol.range,
+            selection_range: symbol.selection_range,
+            children: Vec::new(),
+        });
+    }
+    while !open.is_empty() {
+        close(&mut open, &mut roots);
+    }
+
+    roots
+}
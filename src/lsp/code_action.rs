@@ -0,0 +1,69 @@
+use crate::lsp::diagnostic::Diagnostic;
+use crate::lsp::range::Range;
+use crate::lsp::text_document::TextDocumentIdentifier;
+use crate::lsp::workspace::WorkspaceEdit;
+use serde::{Deserialize, Serialize};
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#codeActionParams
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeActionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub range: Range,
+    pub context: CodeActionContext,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#codeActionContext
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CodeActionContext {
+    /// Diagnostics the client shows over the range.
+    #[serde(default)]
+    pub diagnostics: Vec<Diagnostic>,
+    /// Kinds asked for, prefixes of `CodeActionKind` values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only: Option<Vec<String>>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#codeAction
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeAction {
+    pub title: String,
+    pub kind: CodeActionKind,
+    /// Diagnostics the action resolves.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub diagnostics: Vec<Diagnostic>,
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub is_preferred: bool,
+    pub edit: WorkspaceEdit,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#codeActionKind
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CodeActionKind {
+    #[serde(rename = "quickfix")]
+    QuickFix,
+    #[serde(rename = "refactor.rewrite")]
+    RefactorRewrite,
+}
+
+impl CodeActionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeActionKind::QuickFix => "quickfix",
+            CodeActionKind::RefactorRewrite => "refactor.rewrite",
+        }
+    }
+
+    /// Whether `only` from the context asks for this kind, `only` entries being
+    /// hierarchical prefixes like `refactor`.
+    pub fn matches(&self, only: &[String]) -> bool {
+        let kind = self.as_str();
+        only.iter().any(|o| {
+            kind == o
+                || kind
+                    .strip_prefix(o.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#executeCommandOptions
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecuteCommandOptions {
+    pub commands: Vec<String>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#executeCommandParams
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecuteCommandParams {
+    pub command: String,
+    pub arguments: Option<Vec<Value>>,
+    pub work_done_token: Option<Value>,
+}
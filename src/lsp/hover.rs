@@ -0,0 +1,36 @@
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::lsp::text_document::TextDocumentIdentifier;
+use serde::{Deserialize, Serialize};
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#hoverParams
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HoverParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#hover
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Hover {
+    pub contents: MarkupContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<Range>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#markupContentInnerDefinition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkupContent {
+    pub kind: String,
+    pub value: String,
+}
+
+impl MarkupContent {
+    pub fn markdown(value: impl Into<String>) -> Self {
+        Self {
+            kind: "markdown".into(),
+            value: value.into(),
+        }
+    }
+}
@@ -1,7 +1,14 @@
+use crate::lsp::command::ExecuteCommandOptions;
+use crate::lsp::completion::CompletionOptions;
+use crate::lsp::diagnostic::DiagnosticOptions;
+use crate::lsp::position::PositionEncoding;
+use crate::lsp::rename::RenameOptions;
 use crate::lsp::semantic::SemanticTokensOptions;
 use crate::lsp::text_document::TextDocumentSyncOptions;
+use crate::lsp::workspace::WorkspaceFolder;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use url::Url;
 
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#initialize
 #[derive(Debug, Serialize, Deserialize)]
@@ -9,7 +16,10 @@ use serde_json::Value;
 pub struct InitializeParams {
     pub process_id: Option<i64>,
     pub root_path: Option<String>,
+    pub root_uri: Option<Url>,
     pub capabilities: Option<Value>,
+    pub initialization_options: Option<Value>,
+    pub workspace_folders: Option<Vec<WorkspaceFolder>>,
 }
 
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#initializeResult
@@ -22,7 +32,38 @@ pub struct InitializeResult {
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerCapabilities {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_encoding: Option<PositionEncoding>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text_document_sync: Option<TextDocumentSyncOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_tokens_provider: Option<SemanticTokensOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic_provider: Option<DiagnosticOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_symbol_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_symbol_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folding_range_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_range_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_formatting_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_range_formatting_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hover_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_provider: Option<CompletionOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definition_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename_provider: Option<RenameOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_action_provider: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_command_provider: Option<ExecuteCommandOptions>,
 }
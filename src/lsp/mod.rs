@@ -1,19 +1,51 @@
+pub mod code_action;
+pub mod command;
+pub mod completion;
+pub mod diagnostic;
+pub mod folding;
+pub mod formatting;
+pub mod hover;
 pub mod initialize;
 pub mod initialized;
 pub mod position;
+pub mod progress;
 pub mod range;
+pub mod reference;
+pub mod rename;
+#[cfg(feature = "server")]
+pub mod rpc;
+pub mod selection;
 pub mod semantic;
+pub mod semantic_hub;
 pub mod set_trace;
+pub mod symbol;
 pub mod text_document;
-pub mod semantic_hub;
+pub mod window;
+pub mod workspace;
 
+use crate::lsp::code_action::CodeActionParams;
+use crate::lsp::command::ExecuteCommandParams;
+use crate::lsp::completion::CompletionParams;
+use crate::lsp::diagnostic::DocumentDiagnosticParams;
+use crate::lsp::folding::FoldingRangeParams;
+use crate::lsp::formatting::{DocumentFormattingParams, DocumentRangeFormattingParams};
+use crate::lsp::hover::HoverParams;
 use crate::lsp::initialize::InitializeParams;
 use crate::lsp::initialized::InitializedParams;
+use crate::lsp::progress::WorkDoneProgressCancelParams;
+use crate::lsp::reference::{DefinitionParams, ReferenceParams};
+use crate::lsp::rename::{PrepareRenameParams, RenameParams};
+use crate::lsp::selection::SelectionRangeParams;
 use crate::lsp::semantic::SemanticTokensParams;
 use crate::lsp::set_trace::SetTraceParams;
-use crate::lsp::text_document::{DidChangeTextDocumentParams, DidOpenTextDocumentParams};
+use crate::lsp::symbol::{DocumentSymbolParams, WorkspaceSymbolParams};
+use crate::lsp::text_document::{
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+};
+use crate::lsp::workspace::{DidChangeConfigurationParams, DidChangeWatchedFilesParams};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -37,10 +69,10 @@ pub enum MethodCall {
     Initialize(InitializeParams),
 
     #[serde(rename = "shutdown")]
-    Shutdown(),
+    Shutdown,
 
     #[serde(rename = "exit")]
-    Exit(),
+    Exit,
 
     #[serde(rename = "initialized")]
     Initialized(InitializedParams),
@@ -54,16 +86,79 @@ pub enum MethodCall {
     #[serde(rename = "textDocument/didChange")]
     DidChange(DidChangeTextDocumentParams),
 
+    #[serde(rename = "textDocument/didClose")]
+    DidClose(DidCloseTextDocumentParams),
+
     #[serde(rename = "textDocument/semanticTokens/full")]
     SemanticFull(SemanticTokensParams),
+
+    #[serde(rename = "textDocument/diagnostic")]
+    Diagnostic(DocumentDiagnosticParams),
+
+    #[serde(rename = "textDocument/documentSymbol")]
+    DocumentSymbol(DocumentSymbolParams),
+
+    #[serde(rename = "textDocument/foldingRange")]
+    FoldingRange(FoldingRangeParams),
+
+    #[serde(rename = "textDocument/selectionRange")]
+    SelectionRange(SelectionRangeParams),
+
+    #[serde(rename = "textDocument/formatting")]
+    Formatting(DocumentFormattingParams),
+
+    #[serde(rename = "textDocument/rangeFormatting")]
+    RangeFormatting(DocumentRangeFormattingParams),
+
+    #[serde(rename = "textDocument/hover")]
+    Hover(HoverParams),
+
+    #[serde(rename = "textDocument/completion")]
+    Completion(CompletionParams),
+
+    #[serde(rename = "textDocument/references")]
+    References(ReferenceParams),
+
+    #[serde(rename = "textDocument/definition")]
+    Definition(DefinitionParams),
+
+    #[serde(rename = "textDocument/prepareRename")]
+    PrepareRename(PrepareRenameParams),
+
+    #[serde(rename = "textDocument/rename")]
+    Rename(RenameParams),
+
+    #[serde(rename = "textDocument/codeAction")]
+    CodeAction(CodeActionParams),
+
+    #[serde(rename = "workspace/symbol")]
+    WorkspaceSymbol(WorkspaceSymbolParams),
+
+    #[serde(rename = "workspace/didChangeConfiguration")]
+    DidChangeConfiguration(DidChangeConfigurationParams),
+
+    #[serde(rename = "workspace/didChangeWatchedFiles")]
+    DidChangeWatchedFiles(DidChangeWatchedFilesParams),
+
+    #[serde(rename = "workspace/executeCommand")]
+    ExecuteCommand(ExecuteCommandParams),
+
+    /// Timings, document counts and memory estimate, see `server::status`.
+    #[serde(rename = "jass/serverStatus")]
+    ServerStatus(Option<Value>),
+
+    #[serde(rename = "window/workDoneProgress/cancel")]
+    WorkDoneProgressCancel(WorkDoneProgressCancelParams),
 }
 
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#requestMessage
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RequestMessage {
-    id: Value,
-    method: String,
-    params: Option<Value>,
+    #[serde(default)]
+    pub jsonrpc: String,
+    pub id: Value,
+    pub method: String,
+    pub params: Option<Value>,
 }
 
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#responseMessage
@@ -71,6 +166,36 @@ pub struct RequestMessage {
 pub struct ResponseMessage<T = Value> {
     pub jsonrpc: String,
     pub id: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<T>,
-    pub error: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#responseError
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResponseError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#errorCodes
+#[derive(Debug, Clone, Copy, PartialEq, Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+pub enum ErrorCode {
+    ParseError = -32700,
+    InvalidRequest = -32600,
+    MethodNotFound = -32601,
+    InvalidParams = -32602,
+    InternalError = -32603,
+    RequestCancelled = -32800,
+    RequestFailed = -32803,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#notificationMessage
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationMessage<T = Value> {
+    pub jsonrpc: String,
+    pub method: String,
+    pub params: T,
 }
@@ -2,13 +2,26 @@ use serde::{Deserialize, Serialize};
 use tree_sitter::Point;
 
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#position
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Position {
     pub line: usize,
     pub character: usize,
 }
 
+/// Unit of `Position::character`, agreed on in `initialize`. Columns are bytes of UTF-8
+/// inside the server, `LineList` converts them for the client.
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#positionEncodingKind
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PositionEncoding {
+    #[serde(rename = "utf-8")]
+    Utf8,
+    /// What every client supports, the default of the protocol.
+    #[default]
+    #[serde(rename = "utf-16")]
+    Utf16,
+}
+
 impl Position {
     pub fn point(&self) -> Point {
         Point {
@@ -16,4 +29,11 @@ impl Position {
             column: self.character,
         }
     }
-}
\ No newline at end of file
+
+    pub fn from_point(point: Point) -> Self {
+        Self {
+            line: point.row,
+            character: point.column,
+        }
+    }
+}
@@ -2,9 +2,24 @@ use crate::lsp::position::Position;
 use serde::{Deserialize, Serialize};
 
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#range
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Range {
     pub start: Position,
     pub end: Position,
 }
+
+impl Range {
+    pub fn from_node(node: &tree_sitter::Node) -> Self {
+        Self {
+            start: Position::from_point(node.start_position()),
+            end: Position::from_point(node.end_position()),
+        }
+    }
+
+    /// `other` lies within this range, ends included.
+    pub fn contains(&self, other: &Range) -> bool {
+        let key = |p: &Position| (p.line, p.character);
+        key(&self.start) <= key(&other.start) && key(&other.end) <= key(&self.end)
+    }
+}
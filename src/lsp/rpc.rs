@@ -0,0 +1,347 @@
+use crate::lsp::{ErrorCode, NotificationMessage, RequestMessage, ResponseError};
+use encoding_rs::{Encoding, UTF_8};
+use log::error;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::{self, BufRead, Read, Write};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// Larger bodies are skipped without being read into memory.
+pub const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Reads one frame, `None` on end of stream.
+/// Body is one message or a batch array; a broken frame is `Err`, to be answered with `id: null`.
+/// Lines before a header block, like the body of a frame without `Content-Length`, are
+/// skipped, so the stream picks up again at the next frame.
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#headerPart
+pub fn lsp_read<R: BufRead>(reader: &mut R) -> Option<Result<Value, ResponseError>> {
+    let mut content_length = None;
+    let mut encoding = UTF_8;
+    let mut problem = None;
+    let mut started = false;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let mut header = line.trim_end_matches(['\r', '\n']);
+        if !started {
+            // Next frame may start right after the end of a body we couldn't measure.
+            match header.to_ascii_lowercase().find("content-length:") {
+                Some(at) => header = &header[at..],
+                None if !is_header(header) => continue,
+                None => {}
+            }
+            started = true;
+        }
+        if header.is_empty() {
+            break;
+        }
+
+        let Some((name, value)) = header.split_once(':') else {
+            problem.get_or_insert(format!("Malformed header: {header:?}"));
+            continue;
+        };
+        let value = value.trim();
+
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => match value.parse::<usize>() {
+                Ok(length) => content_length = Some(length),
+                Err(_) => {
+                    problem.get_or_insert(format!("Invalid Content-Length: {value:?}"));
+                }
+            },
+            "content-type" => {
+                if let Some(charset) = charset(value) {
+                    match Encoding::for_label(charset.as_bytes()) {
+                        Some(found) => encoding = found,
+                        None => {
+                            problem.get_or_insert(format!("Unsupported charset: {charset:?}"));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Some(content_length) = content_length else {
+        return Some(Err(parse_error(
+            problem.unwrap_or_else(|| "Missing Content-Length".into()),
+        )));
+    };
+
+    if content_length > MAX_CONTENT_LENGTH {
+        io::copy(&mut reader.take(content_length as u64), &mut io::sink()).ok()?;
+        return Some(Err(parse_error(format!(
+            "Message of {content_length} bytes exceeds {MAX_CONTENT_LENGTH}"
+        ))));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    if let Some(problem) = problem {
+        return Some(Err(parse_error(problem)));
+    }
+
+    let (text, _, malformed) = encoding.decode(&body);
+    if malformed {
+        return Some(Err(parse_error(format!(
+            "Body is not valid {}",
+            encoding.name()
+        ))));
+    }
+    let value = match serde_json::from_str::<Value>(&text) {
+        Ok(value) => value,
+        Err(err) => return Some(Err(parse_error(format!("Invalid JSON: {err}")))),
+    };
+
+    Some(match value {
+        Value::Array(batch) if batch.is_empty() => Err(ResponseError {
+            code: ErrorCode::InvalidRequest,
+            message: "Empty batch".into(),
+        }),
+        value => Ok(value),
+    })
+}
+
+/// `Name: value` with a token for the name.
+fn is_header(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(name, _)| {
+        !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    })
+}
+
+/// `charset` parameter of `Content-Type`, lowercase.
+fn charset(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_ascii_lowercase())
+    })
+}
+
+fn parse_error(message: String) -> ResponseError {
+    ResponseError {
+        code: ErrorCode::ParseError,
+        message,
+    }
+}
+
+/// Writes the whole frame at once, so messages from background threads don't interleave.
+pub fn lsp_send<T: Serialize, W: Write>(writer: &mut W, message: &T) {
+    let msg = serde_json::to_string(message).unwrap();
+    let frame = format!("Content-Length: {}\r\n\r\n{}", msg.len(), msg);
+    if let Err(err) = writer
+        .write_all(frame.as_bytes())
+        .and_then(|_| writer.flush())
+    {
+        error!("Failed to send message: {}", err);
+    }
+}
+
+/// Sends notification to the client from any thread.
+pub fn notify<T: Serialize>(method: &str, params: T) {
+    lsp_send(
+        &mut io::stdout(),
+        &NotificationMessage {
+            jsonrpc: "2.0".into(),
+            method: method.into(),
+            params,
+        },
+    );
+}
+
+static REQUEST_ID: AtomicI64 = AtomicI64::new(1);
+
+/// Callers of `call` waiting for their response, keyed by serialized id.
+type Waiting = HashMap<String, Sender<Result<Value, String>>>;
+static WAITING: Lazy<Mutex<Waiting>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn waiting() -> std::sync::MutexGuard<'static, Waiting> {
+    WAITING.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sends request to the client from any thread, returns its id.
+/// Responses come back through the main loop, which passes them to `respond`.
+pub fn request<T: Serialize>(method: &str, params: T) -> Value {
+    let id = Value::from(REQUEST_ID.fetch_add(1, Ordering::Relaxed));
+    lsp_send(&mut io::stdout(), &request_message(&id, method, params));
+    id
+}
+
+fn request_message<T: Serialize>(id: &Value, method: &str, params: T) -> RequestMessage {
+    RequestMessage {
+        jsonrpc: "2.0".into(),
+        id: id.clone(),
+        method: method.into(),
+        params: serde_json::to_value(params).ok(),
+    }
+}
+
+/// Sends request to the client and waits for its result, `Err` with the message of an
+/// error response or when none came within `timeout`. Never from the main loop, it's
+/// the one reading the response.
+pub fn call<T: Serialize>(method: &str, params: T, timeout: Duration) -> Result<Value, String> {
+    call_with(method, params, timeout, &mut io::stdout())
+}
+
+fn call_with<T: Serialize, W: Write>(
+    method: &str,
+    params: T,
+    timeout: Duration,
+    writer: &mut W,
+) -> Result<Value, String> {
+    let id = Value::from(REQUEST_ID.fetch_add(1, Ordering::Relaxed));
+    let (sender, receiver) = mpsc::channel();
+    waiting().insert(id.to_string(), sender);
+    lsp_send(writer, &request_message(&id, method, params));
+
+    let result = receiver.recv_timeout(timeout);
+    waiting().remove(&id.to_string());
+    result.unwrap_or_else(|_| Err(format!("No response to {method} in {timeout:?}")))
+}
+
+/// Hands a response of the client to the `call` waiting for it, `false` if none is.
+pub fn respond(message: &Value) -> bool {
+    let Some(id) = message.get("id") else {
+        return false;
+    };
+    let Some(sender) = waiting().remove(&id.to_string()) else {
+        return false;
+    };
+    let result = match message.get("error") {
+        Some(error) => Err(error
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("Request failed")
+            .to_string()),
+        None => Ok(message.get("result").cloned().unwrap_or(Value::Null)),
+    };
+    let _ = sender.send(result);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::{BufReader, Cursor};
+
+    fn frame(body: &str) -> String {
+        format!("Content-Length: {}\r\n\r\n{body}", body.len())
+    }
+
+    fn read_all(input: impl Read) -> Vec<Result<Value, ErrorCode>> {
+        let mut reader = BufReader::new(input);
+        std::iter::from_fn(|| lsp_read(&mut reader))
+            .map(|frame| frame.map_err(|err| err.code))
+            .collect()
+    }
+
+    #[test]
+    fn reads_headers_in_any_case() {
+        let input = "content-LENGTH: 8\r\nCONTENT-TYPE: application/vscode-jsonrpc; Charset=\"UTF-8\"\r\n\r\n{\"a\":1}\n";
+        assert_eq!(read_all(Cursor::new(input)), [Ok(json!({"a": 1}))]);
+    }
+
+    #[test]
+    fn decodes_declared_charset() {
+        let mut input =
+            b"Content-Length: 11\r\nContent-Type: application/json; charset=windows-1251\r\n\r\n"
+                .to_vec();
+        input.extend(b"{\"a\":\"\xcf\xf0\xe8\"}");
+        assert_eq!(read_all(Cursor::new(input)), [Ok(json!({"a": "При"}))]);
+
+        let input = "Content-Length: 2\r\nContent-Type: text/plain; charset=klingon\r\n\r\n{}";
+        assert_eq!(read_all(Cursor::new(input)), [Err(ErrorCode::ParseError)]);
+    }
+
+    #[test]
+    fn skips_oversized_message() {
+        let length = MAX_CONTENT_LENGTH + 1;
+        let input = Cursor::new(format!("Content-Length: {length}\r\n\r\n"))
+            .chain(io::repeat(b' ').take(length as u64))
+            .chain(Cursor::new(frame("{}")));
+        assert_eq!(read_all(input), [Err(ErrorCode::ParseError), Ok(json!({}))]);
+    }
+
+    #[test]
+    fn recovers_after_corrupt_message() {
+        let input = [
+            frame("{broken"),
+            "Content-Type: application/json\r\n\r\n{\n  \"lost\": true\n}".to_string(),
+            frame("{\"id\":1}"),
+            "\r\n".to_string(),
+            frame("{\"id\":2}"),
+        ]
+        .concat();
+        assert_eq!(
+            read_all(Cursor::new(input)),
+            [
+                Err(ErrorCode::ParseError),
+                Err(ErrorCode::ParseError),
+                Ok(json!({"id": 1})),
+                Ok(json!({"id": 2})),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_batches() {
+        let input = frame("[{\"id\":1},{\"method\":\"x\"}]") + &frame("[]");
+        assert_eq!(
+            read_all(Cursor::new(input)),
+            [
+                Ok(json!([{"id": 1}, {"method": "x"}])),
+                Err(ErrorCode::InvalidRequest),
+            ]
+        );
+    }
+
+    /// Client that answers each request it reads with `answer(id)`.
+    struct Client(fn(&Value) -> Value);
+
+    impl Write for Client {
+        fn write(&mut self, frame: &[u8]) -> io::Result<usize> {
+            let mut reader = BufReader::new(frame);
+            while let Some(Ok(request)) = lsp_read(&mut reader) {
+                respond(&(self.0)(&request["id"]));
+            }
+            Ok(frame.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn waits_for_result_of_call() {
+        let mut client = Client(|id| json!({"jsonrpc": "2.0", "id": id, "result": {"ok": true}}));
+        let result = call_with("x", (), Duration::from_secs(5), &mut client);
+        assert_eq!(result, Ok(json!({"ok": true})));
+    }
+
+    #[test]
+    fn fails_call_on_error_or_silence() {
+        let mut client = Client(
+            |id| json!({"jsonrpc": "2.0", "id": id, "error": {"code": -1, "message": "no"}}),
+        );
+        let result = call_with("x", (), Duration::from_secs(5), &mut client);
+        assert_eq!(result, Err("no".to_string()));
+
+        let mut silent = Client(|_| json!({"id": -1, "result": null}));
+        assert!(call_with("x", (), Duration::from_millis(10), &mut silent).is_err());
+        assert!(!respond(&json!({"id": -1, "result": null})));
+    }
+}
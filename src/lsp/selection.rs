@@ -0,0 +1,35 @@
+use crate::lsp::position::Position;
+use crate::lsp::range::Range;
+use crate::lsp::text_document::TextDocumentIdentifier;
+use serde::{Deserialize, Serialize};
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#selectionRangeParams
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRangeParams {
+    pub text_document: TextDocumentIdentifier,
+    pub positions: Vec<Position>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#selectionRange
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionRange {
+    pub range: Range,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<SelectionRange>>,
+}
+
+impl SelectionRange {
+    /// Chain from the innermost range out, ranges equal to the previous one skipped.
+    pub fn nest(ranges: impl IntoIterator<Item = Range>) -> Option<Self> {
+        let mut ranges: Vec<Range> = ranges.into_iter().collect();
+        ranges.dedup();
+        ranges.into_iter().rev().fold(None, |parent, range| {
+            Some(Self {
+                range,
+                parent: parent.map(Box::new),
+            })
+        })
+    }
+}
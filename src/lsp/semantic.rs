@@ -65,7 +65,7 @@ pub enum TokenType {
 
 #[derive(Debug, Serialize, Deserialize, EnumIter, Display)]
 #[serde(rename_all = "camelCase")]
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum TokenModifier {
     Declaration,    //	For declarations of symbols.
     Definition,     //	For definitions of symbols, for example, in header files.
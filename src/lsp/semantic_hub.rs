@@ -7,7 +7,7 @@ pub struct Token {
     pub pos: usize,
     pub len: usize,
     pub token_type: TokenType,
-    pub modifier: Option<TokenModifier>,
+    pub modifiers: Vec<TokenModifier>,
 }
 
 #[derive(Debug)]
@@ -29,7 +29,7 @@ impl TokenLine {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SemanticTokenHub {
     pub lines: BTreeMap<usize, TokenLine>,
 }
@@ -41,13 +41,17 @@ impl SemanticTokenHub {
         }
     }
 
+    pub fn token_count(&self) -> usize {
+        self.lines.values().map(|line| line.tokens.len()).sum()
+    }
+
     pub fn add(
         &mut self,
         line: usize,
         pos: usize,
         len: usize,
         token_type: TokenType,
-        modifier: Option<TokenModifier>,
+        modifiers: Vec<TokenModifier>,
     ) -> &mut Self {
         self.lines
             .entry(line)
@@ -57,11 +61,29 @@ impl SemanticTokenHub {
                 pos,
                 len,
                 token_type,
-                modifier,
+                modifiers,
             });
 
         self
     }
+    /// Same tokens with columns and lengths mapped by `character(line, column)`, the way
+    /// the client counts them.
+    pub fn encoded(&self, character: impl Fn(usize, usize) -> usize) -> Self {
+        let mut result = Self::new();
+        for token in self.lines.values().flat_map(|line| &line.tokens) {
+            let pos = character(token.line, token.pos);
+            let end = character(token.line, token.pos + token.len);
+            result.add(
+                token.line,
+                pos,
+                end - pos,
+                token.token_type.clone(),
+                token.modifiers.clone(),
+            );
+        }
+        result
+    }
+
     pub fn data(&self) -> Vec<usize> {
         let mut result = Vec::new();
         let mut line_last = 0;
@@ -76,7 +98,12 @@ impl SemanticTokenHub {
                 result.push(token.pos - token_last);
                 result.push(token.len);
                 result.push(token.token_type.clone() as usize);
-                result.push(token.modifier.as_ref().map_or(0, |m| m.clone() as usize));
+                result.push(
+                    token
+                        .modifiers
+                        .iter()
+                        .fold(0, |bits, m| bits | 1 << m.clone() as usize),
+                );
                 token_last = token.pos;
             }
 
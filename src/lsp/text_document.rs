@@ -23,7 +23,6 @@ pub enum TextDocumentSyncKind {
     Incremental = 2,
 }
 
-
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#didOpenTextDocumentParams
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -49,6 +48,13 @@ pub struct DidChangeTextDocumentParams {
     pub content_changes: Vec<TextDocumentContentChangeEvent>,
 }
 
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#didCloseTextDocumentParams
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
 /// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentIdentifier
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TextDocumentIdentifier {
@@ -67,6 +73,15 @@ pub struct VersionedTextDocumentIdentifier {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TextDocumentContentChangeEvent {
-    pub range: Range,
+    /// Absent when `text` is the whole document.
+    pub range: Option<Range>,
     pub text: String,
 }
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textEdit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#showMessageParams
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShowMessageParams {
+    #[serde(rename = "type")]
+    pub message_type: MessageType,
+    pub message: String,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#messageType
+#[derive(Debug, Clone, Copy, PartialEq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum MessageType {
+    Error = 1,
+    Warning = 2,
+    Info = 3,
+    Log = 4,
+}
@@ -0,0 +1,70 @@
+use crate::lsp::text_document::TextEdit;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::collections::BTreeMap;
+use url::Url;
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#workspaceFolder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceFolder {
+    pub uri: Url,
+    pub name: String,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#didChangeConfigurationParams
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DidChangeConfigurationParams {
+    pub settings: Value,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#didChangeWatchedFilesParams
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DidChangeWatchedFilesParams {
+    pub changes: Vec<FileEvent>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#fileEvent
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileEvent {
+    pub uri: Url,
+    #[serde(rename = "type")]
+    pub change_type: FileChangeType,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#fileChangeType
+#[derive(Debug, Clone, Copy, PartialEq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum FileChangeType {
+    Created = 1,
+    Changed = 2,
+    Deleted = 3,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#registrationParams
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegistrationParams {
+    pub registrations: Vec<Registration>,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#registration
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Registration {
+    pub id: String,
+    pub method: String,
+    pub register_options: Value,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#fileSystemWatcher
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileSystemWatcher {
+    pub glob_pattern: String,
+}
+
+/// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#workspaceEdit
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WorkspaceEdit {
+    pub changes: BTreeMap<Url, Vec<TextEdit>>,
+}
@@ -0,0 +1,933 @@
+use crate::lng::cache::CACHE;
+use crate::lng::index::INDEX;
+use crate::lsp::command::ExecuteCommandOptions;
+use crate::lsp::completion::CompletionOptions;
+use crate::lsp::diagnostic::{DiagnosticOptions, FullDocumentDiagnosticReport};
+use crate::lsp::initialize::{InitializeResult, ServerCapabilities};
+use crate::lsp::rpc::{self, lsp_read, lsp_send, request};
+use crate::lsp::semantic::{
+    SemanticTokens, SemanticTokensLegend, SemanticTokensOptions, ToCamelVec, TokenModifier,
+    TokenType,
+};
+
+use crate::lng::columns::Columns;
+use crate::lng::symbol::Occurrence;
+use crate::lng::Backend;
+use crate::lsp::position::{Position, PositionEncoding};
+use crate::lsp::range::Range;
+use crate::lsp::rename::{RenameOptions, RenameParams};
+use crate::lsp::symbol::{Location, SymbolInformation};
+use crate::lsp::text_document::{TextDocumentSyncKind, TextDocumentSyncOptions, TextEdit};
+use crate::lsp::workspace::{
+    FileChangeType, FileSystemWatcher, Registration, RegistrationParams, WorkspaceEdit,
+};
+use crate::lsp::{ErrorCode, LspCall, LspMessage, MethodCall, ResponseError, ResponseMessage};
+use crate::util::client::{Client, CLIENT};
+use crate::util::isolate::isolate;
+use crate::util::lock::Lock;
+use crate::util::logger;
+use crate::util::metrics::{HistogramSummary, METRICS};
+use crate::util::progress::{self, Progress};
+use crate::util::settings::{Settings, SETTINGS};
+use crate::util::uri_map::URI_MAP;
+use crate::util::workspace::{Workspace, WORKSPACE};
+use crate::{lng, util};
+use log::{debug, error, info};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::io::{self, BufReader};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Instant;
+use tree_sitter::Tree;
+use url::Url;
+
+/// Set by `shutdown`, after which only `exit` is served.
+static SHUT_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Serves LSP over stdio until `exit` or the end of input. Returns the exit code: 0 when
+/// `shutdown` came first, 1 otherwise.
+pub fn run() -> i32 {
+    let stdin = io::stdin();
+    let mut reader = BufReader::new(stdin.lock());
+    let mut writer = io::stdout();
+
+    util::isolate::install_hook();
+
+    'session: while let Some(frame) = lsp_read(&mut reader) {
+        let frame = match frame {
+            Ok(frame) => frame,
+            Err(err) => {
+                error!("Failed to read message: {}", err.message);
+                lsp_send(&mut writer, &error_response(Some(Value::Null), err));
+                continue;
+            }
+        };
+
+        let (answer, running) = serve(frame);
+        if let Some(answer) = answer {
+            lsp_send(&mut writer, &answer);
+        }
+        if !running {
+            break 'session;
+        }
+    }
+
+    match SHUT_DOWN.load(Ordering::Relaxed) {
+        true => 0,
+        false => 1,
+    }
+}
+
+/// Handles a frame, returns what to answer it with and `false` when the server should stop.
+/// A batch is answered with one array of the responses of its members, with nothing when
+/// they were all notifications.
+fn serve(frame: Value) -> (Option<Value>, bool) {
+    let batch = frame.is_array();
+    let messages = match frame {
+        Value::Array(messages) => messages,
+        message => vec![message],
+    };
+
+    let mut replies = Vec::new();
+    let mut running = true;
+    for message in messages {
+        running = dispatch(message, &mut replies);
+        if !running {
+            break;
+        }
+    }
+
+    let answer = match batch {
+        true => (!replies.is_empty()).then_some(Value::Array(replies)),
+        false => replies.pop(),
+    };
+    (answer, running)
+}
+
+/// Responses of the frame being handled, sent once all of its messages are.
+type Replies = Vec<Value>;
+
+fn reply<T: Serialize>(replies: &mut Replies, message: &ResponseMessage<T>) {
+    replies.push(serde_json::to_value(message).unwrap());
+}
+
+fn error_response(id: Option<Value>, error: ResponseError) -> ResponseMessage {
+    ResponseMessage {
+        jsonrpc: "2.0".into(),
+        id,
+        result: None,
+        error: Some(error),
+    }
+}
+
+fn reply_error(replies: &mut Replies, id: Option<Value>, error: ResponseError) {
+    reply(replies, &error_response(id, error));
+}
+
+/// Handles one message of a frame, returns `false` when the server should stop.
+fn dispatch(message: Value, replies: &mut Replies) -> bool {
+    let has_id = message.get("id").is_some_and(|id| !id.is_null());
+    let method = match message.get("method") {
+        Some(Value::String(method)) => method.clone(),
+        _ => String::new(),
+    };
+    let has_method = !method.is_empty();
+
+    // Response to one of our requests.
+    if has_id && !has_method && (message.get("result").is_some() || message.get("error").is_some())
+    {
+        debug!("Response: {}", message);
+        rpc::respond(&message);
+        return true;
+    }
+
+    if SHUT_DOWN.load(Ordering::Relaxed) && method != "exit" {
+        if has_id {
+            reply_error(
+                replies,
+                message.get("id").cloned(),
+                ResponseError {
+                    code: ErrorCode::InvalidRequest,
+                    message: format!("Server is shut down, can't handle {method}"),
+                },
+            );
+        }
+        return true;
+    }
+
+    match serde_json::from_value::<LspMessage>(message) {
+        Ok(LspMessage::Call(call)) => {
+            let id = call.id.clone();
+            // Only a failed edit leaves the text in doubt, a failed read leaves it as it was.
+            let edited = match &call.payload {
+                MethodCall::DidOpen(params) => Some(params.text_document.uri.clone()),
+                MethodCall::DidChange(params) => Some(params.text_document.uri.clone()),
+                _ => None,
+            };
+
+            let started = Instant::now();
+            let handled =
+                logger::request(id.as_ref(), &method, || isolate(|| handle(call, replies)));
+            METRICS.locked().request(&method, started.elapsed());
+
+            match handled {
+                Ok(running) => return running,
+                Err(message) => {
+                    if let Some(uri) = &edited {
+                        lng::mark_stale(uri);
+                    }
+                    if has_id {
+                        reply_error(
+                            replies,
+                            id,
+                            ResponseError {
+                                code: ErrorCode::InternalError,
+                                message,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(LspMessage::RequestMessage(msg)) => {
+            error!("Unexpected request: {:?}", msg);
+            reply_error(
+                replies,
+                Some(msg.id),
+                ResponseError {
+                    code: ErrorCode::MethodNotFound,
+                    message: format!("Unsupported method: {}", msg.method),
+                },
+            );
+        }
+
+        Err(err) if has_method && !has_id => {
+            debug!("Ignored notification: {}", err);
+        }
+
+        Err(err) => {
+            error!("Failed to parse message: {}", err);
+            reply_error(
+                replies,
+                Some(Value::Null),
+                ResponseError {
+                    code: ErrorCode::InvalidRequest,
+                    message: err.to_string(),
+                },
+            );
+        }
+    }
+
+    true
+}
+
+fn apply_settings(value: &Value) {
+    let settings = Settings::from_value(value);
+    logger::configure(&settings);
+    *SETTINGS.locked() = settings;
+}
+
+/// `jass/serverStatus` result, also logged as `stats` on shutdown.
+/// Locks are taken one at a time, handlers nest them in another order.
+fn status() -> Value {
+    let (uptime, requests, parses) = {
+        let metrics = METRICS.locked();
+        let summary = |h| HistogramSummary::from(h);
+        (
+            metrics.uptime(),
+            metrics
+                .requests
+                .iter()
+                .map(|(method, h)| (method.clone(), summary(h)))
+                .collect::<BTreeMap<_, _>>(),
+            metrics
+                .parses
+                .iter()
+                .map(|(lng, h)| (*lng, summary(h)))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    };
+
+    let mut languages: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    let memory = {
+        let map = URI_MAP.locked();
+        for lng in map.lng.values().flatten() {
+            languages.entry(lng.clone()).or_default().0 += 1;
+        }
+        map.memory()
+    };
+    for file in INDEX.locked().files.values() {
+        languages.entry(file.lng.to_string()).or_default().1 += 1;
+    }
+
+    json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "uptimeSeconds": uptime.as_secs(),
+        "documents": {
+            "open": languages.values().map(|(open, _)| open).sum::<usize>(),
+            "indexed": languages.values().map(|(_, indexed)| indexed).sum::<usize>(),
+            "byLanguage": languages
+                .iter()
+                .map(|(lng, (open, indexed))| (lng.clone(), json!({ "open": open, "indexed": indexed })))
+                .collect::<Map<_, _>>(),
+        },
+        "memory": memory,
+        "requests": requests,
+        "parses": parses,
+    })
+}
+
+/// Runs `f` on the open document, `T::default()` if it isn't open or has no backend.
+/// `f` converts the positions it takes and gives with the `Columns` of the document.
+fn with_document<T: Default>(
+    uri: &Url,
+    f: impl FnOnce(&'static Backend, &Tree, &Columns) -> T,
+) -> T {
+    let encoding = CLIENT.locked().position_encoding;
+    let map = URI_MAP.locked();
+    let backend = map
+        .lng
+        .get(uri)
+        .and_then(Option::as_deref)
+        .and_then(lng::backend);
+    let tree = map.tree.get(uri).and_then(Option::as_ref);
+    match (backend, tree, map.line_list.get(uri)) {
+        (Some(backend), Some(tree), Some(line_list)) => f(
+            backend,
+            tree,
+            &Columns {
+                uri,
+                line_list,
+                encoding,
+            },
+        ),
+        _ => T::default(),
+    }
+}
+
+/// Cursor in the client's unit to byte columns of the open document.
+fn cursor(uri: &Url, position: Position, encoding: PositionEncoding) -> Position {
+    lng::columns::open(uri, encoding, |columns| columns.incoming(position)).unwrap_or(position)
+}
+
+/// Declarations and uses of the name under the cursor across the workspace.
+fn occurrences(uri: &Url, position: Position) -> Vec<(Url, Occurrence)> {
+    let settings = SETTINGS.locked().clone();
+    let encoding = CLIENT.locked().position_encoding;
+    match lng::reference::at(uri, cursor(uri, position, encoding), &settings) {
+        Some((backend, name)) => lng::reference::find(backend, &name.key, &settings, encoding),
+        None => Vec::new(),
+    }
+}
+
+/// Edits renaming the name under the cursor everywhere, or why it can't be renamed.
+fn rename(params: &RenameParams) -> Result<WorkspaceEdit, String> {
+    let settings = SETTINGS.locked().clone();
+    let encoding = CLIENT.locked().position_encoding;
+    let uri = &params.text_document.uri;
+    let (backend, name) =
+        lng::reference::at(uri, cursor(uri, params.position, encoding), &settings)
+            .ok_or("Nothing to rename here")?;
+    let invalid = backend
+        .rename
+        .ok_or("Names can't be renamed in this language")?;
+    if let Some(message) = invalid(&params.new_name) {
+        return Err(message);
+    }
+
+    let found = lng::reference::find(backend, &name.key, &settings, encoding);
+    if found.is_empty() {
+        return Err("Nothing declares this name".into());
+    }
+    let mut edit = WorkspaceEdit::default();
+    for (uri, occurrence) in found {
+        edit.changes.entry(uri).or_default().push(TextEdit {
+            range: occurrence.range,
+            new_text: params.new_name.clone(),
+        });
+    }
+    Ok(edit)
+}
+
+/// Formatter edits with the `jass.format` settings, none without a formatter.
+fn format(uri: &Url, range: Option<Range>) -> Vec<TextEdit> {
+    let settings = SETTINGS.locked().format.clone();
+    with_document(uri, |backend, tree, columns| {
+        let range = columns.incoming(range);
+        let edits = backend
+            .format
+            .map(|format| format(tree, columns.line_list, &settings, range));
+        columns.outgoing(lng::format::text_edits(
+            columns.line_list,
+            &edits.unwrap_or_default(),
+        ))
+    })
+}
+
+/// What the compiled-in backends support, nothing document related without any.
+fn capabilities() -> ServerCapabilities {
+    if lng::BACKENDS.is_empty() {
+        return ServerCapabilities::default();
+    }
+    ServerCapabilities {
+        position_encoding: Some(CLIENT.locked().position_encoding),
+        text_document_sync: Some(TextDocumentSyncOptions {
+            open_close: Some(true),
+            change: Some(TextDocumentSyncKind::Incremental),
+        }),
+        semantic_tokens_provider: Some(SemanticTokensOptions {
+            legend: SemanticTokensLegend {
+                token_types: <TokenType as ToCamelVec>::get_vec(),
+                token_modifiers: <TokenModifier as ToCamelVec>::get_vec(),
+            },
+            full: true,
+        }),
+        diagnostic_provider: Some(DiagnosticOptions {
+            inter_file_dependencies: false,
+            workspace_diagnostics: false,
+        }),
+        workspace_symbol_provider: Some(true),
+        document_symbol_provider: Some(true),
+        folding_range_provider: Some(lng::BACKENDS.iter().any(|b| b.folding.is_some())),
+        selection_range_provider: Some(lng::BACKENDS.iter().any(|b| b.selection.is_some())),
+        document_formatting_provider: Some(lng::BACKENDS.iter().any(|b| b.format.is_some())),
+        document_range_formatting_provider: Some(lng::BACKENDS.iter().any(|b| b.format.is_some())),
+        hover_provider: Some(lng::BACKENDS.iter().any(|b| b.hover.is_some())),
+        completion_provider: lng::BACKENDS
+            .iter()
+            .any(|b| b.completion.is_some())
+            .then(|| CompletionOptions {
+                trigger_characters: vec!["[".into(), "=".into(), ",".into()],
+            }),
+        references_provider: Some(lng::BACKENDS.iter().any(|b| b.references.is_some())),
+        definition_provider: Some(lng::BACKENDS.iter().any(|b| b.references.is_some())),
+        rename_provider: lng::BACKENDS
+            .iter()
+            .any(|b| b.references.is_some() && b.rename.is_some())
+            .then_some(RenameOptions {
+                prepare_provider: true,
+            }),
+        code_action_provider: Some(lng::BACKENDS.iter().any(|b| b.actions.is_some())),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![lng::publish::CHECK_WORKSPACE.into()],
+        }),
+    }
+}
+
+/// Handles one call, returns `false` when the server should stop.
+fn handle(call: LspCall, replies: &mut Replies) -> bool {
+    match call.payload {
+        MethodCall::Initialize(params) => {
+            *WORKSPACE.locked() = Workspace::from_params(&params);
+            if let Some(options) = &params.initialization_options {
+                apply_settings(options);
+            }
+            if let Some(capabilities) = &params.capabilities {
+                *CLIENT.locked() = Client::from_capabilities(capabilities);
+            }
+            lng::reset();
+
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: Some(Value::from(call.id)),
+                    result: Some(InitializeResult {
+                        capabilities: capabilities(),
+                    }),
+                    error: None,
+                },
+            );
+        }
+        MethodCall::Shutdown => {
+            CACHE.locked().save();
+            info!(target: "stats", "{}", status());
+            SHUT_DOWN.store(true, Ordering::Relaxed);
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(json!(null)),
+                    error: None,
+                },
+            );
+        }
+        MethodCall::Exit => return false,
+
+        MethodCall::Initialized(_) => {
+            if CLIENT.locked().watch_files && !lng::BACKENDS.is_empty() {
+                let globs = lng::BACKENDS
+                    .iter()
+                    .flat_map(|b| b.extensions.iter())
+                    .map(|ext| format!("**/*.{ext}"))
+                    .chain(["**/.jass/**".to_string()])
+                    .map(|glob_pattern| FileSystemWatcher { glob_pattern })
+                    .collect::<Vec<_>>();
+                request(
+                    "client/registerCapability",
+                    RegistrationParams {
+                        registrations: vec![Registration {
+                            id: "watched-files".into(),
+                            method: "workspace/didChangeWatchedFiles".into(),
+                            register_options: json!({ "watchers": globs }),
+                        }],
+                    },
+                );
+            }
+            lng::index::spawn_scan();
+        }
+        MethodCall::SetTrace(params) => {
+            logger::set_trace(&params.value, &SETTINGS.locked());
+        }
+
+        MethodCall::DidChangeConfiguration(params) => apply_settings(&params.settings),
+
+        MethodCall::WorkDoneProgressCancel(params) => progress::cancel(&params.token),
+
+        MethodCall::DidOpen(params) => {
+            let document = params.text_document;
+            if let Some(backend) = lng::backend(&document.language_id) {
+                lng::open(&document.uri, backend, &document.text);
+                lng::publish::publish(&document.uri);
+            }
+        }
+
+        MethodCall::DidChange(params) => {
+            lng::change(&params.text_document.uri, params.content_changes);
+            lng::publish::schedule(&params.text_document.uri);
+        }
+
+        MethodCall::DidClose(params) => {
+            let uri = &params.text_document.uri;
+            URI_MAP.locked().remove(uri);
+            lng::publish::clear(uri);
+            match uri.to_file_path() {
+                Ok(path) => lng::index::update_path(&path),
+                Err(_) => lng::index::remove(uri),
+            }
+        }
+
+        MethodCall::DidChangeWatchedFiles(params) => {
+            for change in params.changes {
+                // Highlight queries and schemas, not sources.
+                if change.uri.path().contains("/.jass/") {
+                    lng::reset();
+                    continue;
+                }
+                match (change.change_type, change.uri.to_file_path()) {
+                    (FileChangeType::Deleted, _) | (_, Err(_)) => lng::index::remove(&change.uri),
+                    (_, Ok(path)) => lng::index::update_path(&path),
+                }
+            }
+        }
+
+        MethodCall::ExecuteCommand(params) => match params.command.as_str() {
+            lng::publish::CHECK_WORKSPACE => {
+                // Runs in background, so cancel notifications get through the main loop.
+                thread::spawn(move || {
+                    let checked = isolate(|| {
+                        let mut progress =
+                            Progress::with_token(params.work_done_token, "Checking workspace");
+                        let check = lng::publish::check_workspace(&mut progress);
+                        if let Some(check) = &check {
+                            progress.end(format!(
+                                "{} files, {} errors, {} warnings",
+                                check.files, check.errors, check.warnings
+                            ));
+                        }
+                        check
+                    });
+                    let mut writer = io::stdout();
+                    let error = match checked {
+                        Ok(Some(check)) => {
+                            return lsp_send(
+                                &mut writer,
+                                &ResponseMessage {
+                                    jsonrpc: "2.0".into(),
+                                    id: call.id,
+                                    result: Some(check),
+                                    error: None,
+                                },
+                            );
+                        }
+                        Ok(None) => ResponseError {
+                            code: ErrorCode::RequestCancelled,
+                            message: "Workspace check cancelled".into(),
+                        },
+                        Err(message) => ResponseError {
+                            code: ErrorCode::InternalError,
+                            message,
+                        },
+                    };
+                    lsp_send(&mut writer, &error_response(call.id, error));
+                });
+            }
+            command => reply_error(
+                replies,
+                call.id,
+                ResponseError {
+                    code: ErrorCode::InvalidParams,
+                    message: format!("Unknown command: {command}"),
+                },
+            ),
+        },
+
+        MethodCall::ServerStatus(_) => {
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(status()),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::WorkspaceSymbol(params) => {
+            let encoding = CLIENT.locked().position_encoding;
+            let index = INDEX.locked();
+            let symbols: Vec<SymbolInformation> = index
+                .search(&params.query, encoding)
+                .into_iter()
+                .map(|(uri, symbol, range)| SymbolInformation {
+                    name: symbol.name.clone(),
+                    kind: symbol.kind,
+                    location: Location {
+                        uri: uri.clone(),
+                        range,
+                    },
+                    container_name: symbol.container.clone(),
+                })
+                .collect();
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(symbols),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::DocumentSymbol(params) => {
+            let symbols = with_document(&params.text_document.uri, |backend, tree, columns| {
+                columns.outgoing(lng::symbol::outline(&(backend.symbols)(
+                    tree,
+                    columns.line_list,
+                )))
+            });
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(symbols),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::FoldingRange(params) => {
+            // Lines only, nothing to convert.
+            let folds = with_document(&params.text_document.uri, |backend, tree, columns| {
+                backend
+                    .folding
+                    .map(|folding| folding(tree, columns.line_list))
+            });
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(folds.unwrap_or_default()),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::SelectionRange(params) => {
+            let ranges = with_document(&params.text_document.uri, |backend, tree, columns| {
+                let positions = columns.incoming(params.positions);
+                backend.selection.map(|selection| {
+                    columns.outgoing(selection(tree, columns.line_list, &positions))
+                })
+            });
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(ranges.unwrap_or_default()),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::Formatting(params) => {
+            let edits = format(&params.text_document.uri, None);
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(edits),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::RangeFormatting(params) => {
+            let edits = format(&params.text_document.uri, Some(params.range));
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(edits),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::Hover(params) => {
+            let hover = with_document(&params.text_document.uri, |backend, tree, columns| {
+                let position = columns.incoming(params.position);
+                backend.hover.and_then(|hover| {
+                    columns.outgoing(hover(
+                        tree,
+                        columns.line_list,
+                        &params.text_document.uri,
+                        position,
+                    ))
+                })
+            });
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(json!(hover)),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::Completion(params) => {
+            // Items have no positions, only the cursor needs converting.
+            let items = with_document(&params.text_document.uri, |backend, tree, columns| {
+                let position = columns.incoming(params.position);
+                backend.completion.map(|completion| {
+                    completion(tree, columns.line_list, &params.text_document.uri, position)
+                })
+            });
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(items.unwrap_or_default()),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::References(params) => {
+            let include_declaration = params.context.include_declaration;
+            let locations = occurrences(&params.text_document.uri, params.position)
+                .into_iter()
+                .filter(|(_, o)| include_declaration || !o.declaration)
+                .map(|(uri, o)| Location {
+                    uri,
+                    range: o.range,
+                })
+                .collect::<Vec<_>>();
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(locations),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::Definition(params) => {
+            let locations = occurrences(&params.text_document.uri, params.position)
+                .into_iter()
+                .filter(|(_, o)| o.declaration)
+                .map(|(uri, o)| Location {
+                    uri,
+                    range: o.range,
+                })
+                .collect::<Vec<_>>();
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(locations),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::PrepareRename(params) => {
+            let settings = SETTINGS.locked().clone();
+            let encoding = CLIENT.locked().position_encoding;
+            let uri = &params.text_document.uri;
+            let range = lng::reference::at(uri, cursor(uri, params.position, encoding), &settings)
+                .filter(|(backend, name)| {
+                    backend.rename.is_some()
+                        && !lng::reference::find(backend, &name.key, &settings, encoding).is_empty()
+                })
+                .and_then(|(_, name)| {
+                    lng::columns::open(uri, encoding, |columns| columns.outgoing(name.range))
+                });
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(json!(range)),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::Rename(params) => match rename(&params) {
+            Ok(edit) => reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(edit),
+                    error: None,
+                },
+            ),
+            Err(message) => reply_error(
+                replies,
+                call.id,
+                ResponseError {
+                    code: ErrorCode::RequestFailed,
+                    message,
+                },
+            ),
+        },
+
+        MethodCall::CodeAction(params) => {
+            let uri = params.text_document.uri.clone();
+            let only = params.context.only.clone();
+            let actions = with_document(&uri, |backend, tree, columns| {
+                let params = columns.incoming(params);
+                backend.actions.map(|actions| {
+                    columns.outgoing(actions(
+                        tree,
+                        columns.line_list,
+                        &uri,
+                        params.range,
+                        &params.context.diagnostics,
+                    ))
+                })
+            });
+            let only = only.as_deref();
+            let actions = actions
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|a| only.is_none_or(|only| a.kind.matches(only)))
+                .collect::<Vec<_>>();
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: call.id,
+                    result: Some(actions),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::SemanticFull(params) => {
+            let encoding = CLIENT.locked().position_encoding;
+            let mut map = URI_MAP.locked();
+            let entry = map.entry(&params.text_document.uri);
+            let line_list = &*entry.line_list;
+            let semantic = entry
+                .semantic
+                .encoded(|row, column| line_list.character(row, column, encoding));
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: Some(Value::from(call.id)),
+                    result: Some(SemanticTokens {
+                        data: semantic.data(),
+                    }),
+                    error: None,
+                },
+            );
+        }
+
+        MethodCall::Diagnostic(params) => {
+            reply(
+                replies,
+                &ResponseMessage {
+                    jsonrpc: "2.0".into(),
+                    id: Some(Value::from(call.id)),
+                    result: Some(FullDocumentDiagnosticReport {
+                        kind: "full".into(),
+                        items: lng::publish::refresh(&params.text_document.uri),
+                    }),
+                    error: None,
+                },
+            );
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_batch_with_one_array() {
+        let (answer, running) = serve(json!([
+            {"jsonrpc": "2.0", "method": "$/unknown"},
+            {"jsonrpc": "2.0", "id": 1, "method": "unknown/first"},
+            {"jsonrpc": "2.0", "id": 2, "method": "unknown/second"},
+        ]));
+        assert!(running);
+        let answer = answer.unwrap();
+        let ids: Vec<&Value> = answer
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|response| &response["id"])
+            .collect();
+        assert_eq!(ids, [&json!(1), &json!(2)]);
+    }
+
+    #[test]
+    fn leaves_batch_of_notifications_unanswered() {
+        let (answer, running) = serve(json!([
+            {"jsonrpc": "2.0", "method": "$/unknown"},
+            {"jsonrpc": "2.0", "method": "$/other"},
+        ]));
+        assert!(running);
+        assert_eq!(answer, None);
+    }
+
+    #[test]
+    fn answers_single_message_without_array() {
+        let (answer, _) = serve(json!({"jsonrpc": "2.0", "id": 3, "method": "unknown"}));
+        assert_eq!(answer.unwrap()["id"], json!(3));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::lsp::position::PositionEncoding;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+use std::sync::Mutex;
+
+pub static CLIENT: Lazy<Mutex<Client>> = Lazy::new(|| Mutex::new(Client::default()));
+
+/// What the client told about itself in `initialize`.
+#[derive(Debug, Default)]
+pub struct Client {
+    /// Client asks for diagnostics with `textDocument/diagnostic`, so we don't push them.
+    pub pull_diagnostics: bool,
+    /// Client lets us register `workspace/didChangeWatchedFiles` watchers.
+    pub watch_files: bool,
+    /// Client shows `$/progress` of tokens we create with `window/workDoneProgress/create`.
+    pub work_done_progress: bool,
+    /// UTF-8 when the client offers it, saving conversions of every position.
+    pub position_encoding: PositionEncoding,
+}
+
+impl Client {
+    pub fn from_capabilities(capabilities: &Value) -> Self {
+        Self {
+            pull_diagnostics: !capabilities
+                .pointer("/textDocument/diagnostic")
+                .unwrap_or(&Value::Null)
+                .is_null(),
+            watch_files: capabilities
+                .pointer("/workspace/didChangeWatchedFiles/dynamicRegistration")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            work_done_progress: capabilities
+                .pointer("/window/workDoneProgress")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            position_encoding: match capabilities
+                .pointer("/general/positionEncodings")
+                .and_then(Value::as_array)
+                .is_some_and(|offered| offered.iter().any(|e| e == "utf-8"))
+            {
+                true => PositionEncoding::Utf8,
+                false => PositionEncoding::Utf16,
+            },
+        }
+    }
+}
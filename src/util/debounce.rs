@@ -0,0 +1,60 @@
+use crate::util::isolate::isolate;
+use crate::util::lock::Lock;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Calls `action` for a key once no new `touch` for it came within `delay`.
+pub struct Debouncer<K> {
+    tx: Mutex<Sender<K>>,
+}
+
+impl<K: Eq + Hash + Clone + Send + 'static> Debouncer<K> {
+    pub fn new(delay: Duration, action: impl Fn(&K) + Send + 'static) -> Self {
+        let (tx, rx) = mpsc::channel::<K>();
+
+        thread::spawn(move || {
+            let mut pending: HashMap<K, Instant> = HashMap::new();
+
+            loop {
+                let timeout = pending
+                    .values()
+                    .min()
+                    .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+
+                let received = match timeout {
+                    Some(timeout) => rx.recv_timeout(timeout),
+                    None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                };
+
+                match received {
+                    Ok(key) => {
+                        pending.insert(key, Instant::now() + delay);
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+
+                let now = Instant::now();
+                let due: Vec<K> = pending
+                    .iter()
+                    .filter(|(_, deadline)| **deadline <= now)
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                for key in due {
+                    pending.remove(&key);
+                    let _ = isolate(|| action(&key));
+                }
+            }
+        });
+
+        Self { tx: Mutex::new(tx) }
+    }
+
+    pub fn touch(&self, key: K) {
+        let _ = self.tx.locked().send(key);
+    }
+}
@@ -0,0 +1,191 @@
+use encoding_rs::{UTF_16BE, UTF_16LE, WINDOWS_1251, WINDOWS_1252};
+use std::fmt::{Display, Formatter};
+
+/// Encoding a file came in, so it can be written back byte for byte.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Encoding {
+    #[default]
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    /// Cyrillic, what most old maps of our community use.
+    Windows1251,
+    /// Western European.
+    Windows1252,
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf8Bom => "UTF-8 with BOM",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Windows1251 => "windows-1251",
+            Encoding::Windows1252 => "windows-1252",
+        })
+    }
+}
+
+impl Encoding {
+    pub fn is_utf8(&self) -> bool {
+        matches!(self, Encoding::Utf8 | Encoding::Utf8Bom)
+    }
+}
+
+#[derive(Debug)]
+pub struct Decoded {
+    pub text: String,
+    pub encoding: Encoding,
+}
+
+/// BOM first, then UTF-8 validity, then a guess between windows-1251 and windows-1252.
+/// A BOM whose text doesn't encode back to the same bytes is a coincidence, not a BOM.
+/// Single byte decoders of `encoding_rs` map all 256 bytes, so the guess is always lossless.
+pub fn decode(bytes: &[u8]) -> Decoded {
+    if let Some((text, encoding)) = bom(bytes)
+        && encode(&text, encoding) == bytes
+    {
+        return Decoded { text, encoding };
+    }
+
+    let (text, encoding) = if let Ok(text) = std::str::from_utf8(bytes) {
+        (text.to_string(), Encoding::Utf8)
+    } else if looks_cyrillic(bytes) {
+        (
+            WINDOWS_1251
+                .decode_without_bom_handling(bytes)
+                .0
+                .into_owned(),
+            Encoding::Windows1251,
+        )
+    } else {
+        (
+            WINDOWS_1252
+                .decode_without_bom_handling(bytes)
+                .0
+                .into_owned(),
+            Encoding::Windows1252,
+        )
+    };
+    Decoded { text, encoding }
+}
+
+fn bom(bytes: &[u8]) -> Option<(String, Encoding)> {
+    if let Some(rest) = bytes.strip_prefix(b"\xEF\xBB\xBF") {
+        return Some((
+            String::from_utf8_lossy(rest).into_owned(),
+            Encoding::Utf8Bom,
+        ));
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFF\xFE") {
+        let text = UTF_16LE.decode_without_bom_handling(rest).0;
+        return Some((text.into_owned(), Encoding::Utf16Le));
+    }
+    let rest = bytes.strip_prefix(b"\xFE\xFF")?;
+    let text = UTF_16BE.decode_without_bom_handling(rest).0;
+    Some((text.into_owned(), Encoding::Utf16Be))
+}
+
+/// Bytes to write back, the same as were read for unchanged text.
+pub fn encode(text: &str, encoding: Encoding) -> Vec<u8> {
+    match encoding {
+        Encoding::Utf8 => text.as_bytes().to_vec(),
+        Encoding::Utf8Bom => [b"\xEF\xBB\xBF".as_slice(), text.as_bytes()].concat(),
+        // encoding_rs encodes UTF-16 as UTF-8 per WHATWG, so by hand.
+        Encoding::Utf16Le => [0xFF, 0xFE]
+            .into_iter()
+            .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+            .collect(),
+        Encoding::Utf16Be => [0xFE, 0xFF]
+            .into_iter()
+            .chain(text.encode_utf16().flat_map(u16::to_be_bytes))
+            .collect(),
+        Encoding::Windows1251 => WINDOWS_1251.encode(text).0.into_owned(),
+        Encoding::Windows1252 => WINDOWS_1252.encode(text).0.into_owned(),
+    }
+}
+
+/// In windows-1251 letters live in `0xC0..=0xFF` and come in runs, like words do.
+/// In windows-1252 the same bytes are accented latin letters surrounded by ASCII ones.
+fn looks_cyrillic(bytes: &[u8]) -> bool {
+    let letter = |b: u8| b >= 0xC0 || b == 0xA8 || b == 0xB8; // Ё ё
+    let mut cyrillic = 0;
+    let mut latin = 0;
+
+    for window in bytes.windows(2) {
+        let (a, b) = (window[0], window[1]);
+        match (letter(a), letter(b)) {
+            (true, true) => cyrillic += 1,
+            (true, false) if b.is_ascii_alphabetic() => latin += 1,
+            (false, true) if a.is_ascii_alphabetic() => latin += 1,
+            _ => {}
+        }
+    }
+
+    cyrillic >= latin
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoded(bytes: &[u8]) -> (String, Encoding) {
+        let decoded = decode(bytes);
+        assert_eq!(encode(&decoded.text, decoded.encoding), bytes);
+        (decoded.text, decoded.encoding)
+    }
+
+    #[test]
+    fn reads_byte_order_marks() {
+        assert_eq!(
+            decoded(b"\xEF\xBB\xBF[hfoo]"),
+            ("[hfoo]".to_string(), Encoding::Utf8Bom)
+        );
+        assert_eq!(
+            decoded(b"\xFF\xFE[\x00\x1F\x04]\x00"),
+            ("[П]".to_string(), Encoding::Utf16Le)
+        );
+        assert_eq!(
+            decoded(b"\xFE\xFF\x00[\x04\x1F\x00]"),
+            ("[П]".to_string(), Encoding::Utf16Be)
+        );
+    }
+
+    #[test]
+    fn ignores_marks_text_doesnt_follow() {
+        // Odd length can't be UTF-16, it's windows-1251 text starting with `яю`.
+        assert_eq!(
+            decoded(b"\xFF\xFE\xF2\xE0\xEA"),
+            ("яютак".to_string(), Encoding::Windows1251)
+        );
+    }
+
+    #[test]
+    fn prefers_utf8() {
+        assert_eq!(
+            decoded("Name=Пехотинец".as_bytes()),
+            ("Name=Пехотинец".to_string(), Encoding::Utf8)
+        );
+        assert_eq!(decoded(b""), (String::new(), Encoding::Utf8));
+    }
+
+    #[test]
+    fn tells_cyrillic_from_western_text() {
+        let cyrillic = WINDOWS_1251.encode("Name=Пехотинец Ёлки").0;
+        assert_eq!(
+            decoded(&cyrillic),
+            ("Name=Пехотинец Ёлки".to_string(), Encoding::Windows1251)
+        );
+
+        let western = WINDOWS_1252.encode("Name=Soldat de la Légion, déjà").0;
+        assert_eq!(
+            decoded(&western),
+            (
+                "Name=Soldat de la Légion, déjà".to_string(),
+                Encoding::Windows1252
+            )
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use log::error;
+use std::backtrace::Backtrace;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Sends panics with a backtrace to the log instead of bare stderr.
+pub fn install_hook() {
+    panic::set_hook(Box::new(|info| {
+        error!("{info}\n{}", Backtrace::force_capture());
+    }));
+}
+
+/// Runs `f`, turning a panic into `Err` with its message, so one bad handler
+/// doesn't take the whole server down.
+pub fn isolate<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "panic".to_string()
+        }
+    })
+}
@@ -1,78 +1,329 @@
-use crate::lsp::position::Position;
+use crate::lsp::position::{Position, PositionEncoding};
+use tree_sitter::{InputEdit, Point};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct LineList {
     pub lines: Vec<String>,
+    /// Text ends with a line break, the empty line after it is not in `lines`.
+    pub ends_with_newline: bool,
 }
 
 impl LineList {
     pub fn new() -> Self {
-        Self { lines: Vec::new() }
+        Self::default()
     }
 
+    /// Text is expected to be decoded already, see `util::encoding`.
     pub fn set_text(&mut self, text: impl AsRef<[u8]>) {
-        let text = std::str::from_utf8(text.as_ref()).expect("Invalid UTF-8");
-        self.lines = text.lines().map(str::to_string).collect();
+        let text = String::from_utf8_lossy(text.as_ref());
+        self.lines = split(&text);
+        self.trim_end();
     }
 
     pub fn to_text(&self) -> String {
-        self.lines.join("\n")
+        let mut text = self.lines.join("\n");
+        if self.ends_with_newline {
+            text.push('\n');
+        }
+        text
     }
 
-    pub fn position_to_offset(&self, pos: &Position) -> Option<usize> {
-        if pos.line >= self.lines.len() {
-            return None;
+    /// Moves the empty line after a final line break out of `lines`.
+    fn trim_end(&mut self) {
+        self.ends_with_newline =
+            self.lines.len() > 1 && self.lines.last().is_some_and(String::is_empty);
+        if self.ends_with_newline {
+            self.lines.pop();
+        }
+    }
+
+    /// Text between two points, lines joined with `\n`.
+    pub fn slice(&self, start: Point, end: Point) -> String {
+        let mut result = String::new();
+        for row in start.row..=end.row {
+            let Some(line) = self.lines.get(row) else {
+                break;
+            };
+            let from = if row == start.row { start.column } else { 0 };
+            let to = if row == end.row {
+                end.column
+            } else {
+                line.len()
+            };
+            if row > start.row {
+                result.push('\n');
+            }
+            result.push_str(line.get(from.min(to)..to.min(line.len())).unwrap_or(""));
+        }
+        result
+    }
+
+    /// `character` of byte `column` on line `row` in the client's unit. Columns inside a
+    /// character count from its start, columns past the line end stay as far past it.
+    pub fn character(&self, row: usize, column: usize, encoding: PositionEncoding) -> usize {
+        let Some(line) = self
+            .lines
+            .get(row)
+            .filter(|_| encoding == PositionEncoding::Utf16)
+        else {
+            return column;
+        };
+        let mut end = column.min(line.len());
+        while !line.is_char_boundary(end) {
+            end -= 1;
+        }
+        line[..end].encode_utf16().count() + column.saturating_sub(line.len())
+    }
+
+    /// Byte column of `character` on line `row`, the reverse of [`Self::character`].
+    pub fn column(&self, row: usize, character: usize, encoding: PositionEncoding) -> usize {
+        let Some(line) = self
+            .lines
+            .get(row)
+            .filter(|_| encoding == PositionEncoding::Utf16)
+        else {
+            return character;
+        };
+        let mut units = 0;
+        for (column, c) in line.char_indices() {
+            if units >= character {
+                return column;
+            }
+            units += c.len_utf16();
         }
-        let mut offset = 0;
-        for i in 0..pos.line {
-            offset += self.lines.get(i)?.len() + 1; // +1 за '\n'
+        line.len() + character.saturating_sub(units)
+    }
+
+    pub fn to_client(&self, position: Position, encoding: PositionEncoding) -> Position {
+        Position {
+            line: position.line,
+            character: self.character(position.line, position.character, encoding),
+        }
+    }
+
+    pub fn from_client(&self, position: Position, encoding: PositionEncoding) -> Position {
+        Position {
+            line: position.line,
+            character: self.column(position.line, position.character, encoding),
+        }
+    }
+
+    /// `position` put on the text: columns past the line end go to its end, columns inside
+    /// a character to its start. `None` for lines the text doesn't have, the empty one after
+    /// a final line break it has.
+    pub fn clamp(&self, position: Position) -> Option<Position> {
+        let line = match self.lines.get(position.line) {
+            Some(line) => line.as_str(),
+            None if position.line == self.lines.len()
+                && (self.ends_with_newline || self.lines.is_empty()) =>
+            {
+                ""
+            }
+            None => return None,
+        };
+        let mut character = position.character.min(line.len());
+        while !line.is_char_boundary(character) {
+            character -= 1;
         }
-        Some(offset + pos.character)
+        Some(Position {
+            line: position.line,
+            character,
+        })
+    }
+
+    /// Byte offset of `pos` in [`Self::to_text`], clamped like [`Self::clamp`].
+    pub fn position_to_offset(&self, pos: &Position) -> Option<usize> {
+        let pos = self.clamp(*pos)?;
+        let before: usize = self.lines[..pos.line].iter().map(|l| l.len() + 1).sum();
+        Some(before + pos.character)
     }
 
-    pub fn point_from_offset(&self, offset: usize) -> tree_sitter::Point {
+    pub fn point_from_offset(&self, offset: usize) -> Point {
         let mut total = 0;
         for (row, line) in self.lines.iter().enumerate() {
             let len = line.len() + 1;
             if total + len > offset {
-                return tree_sitter::Point {
+                return Point {
                     row,
                     column: offset - total,
                 };
             }
             total += len;
         }
+        if self.ends_with_newline {
+            return Point {
+                row: self.lines.len(),
+                column: 0,
+            };
+        }
         // Если offset за концом текста
         let last_row = self.lines.len().saturating_sub(1);
         let last_col = self.lines.get(last_row).map_or(0, |l| l.len());
-        tree_sitter::Point {
+        Point {
             row: last_row,
             column: last_col,
         }
     }
 
-    pub fn apply_change(&mut self, start: &Position, end: &Position, new_text: &str) {
-        let start_line = start.line;
-        let start_col = start.character;
-        let end_line = end.line;
-        let end_col = end.character;
-
-        let before = &self.lines[start_line][..start_col];
-        let after = &self.lines[end_line][end_col..];
-
-        let new_lines: Vec<String> = new_text.lines().map(|s| s.to_string()).collect();
-        let replacement = match new_lines.len() {
-            0 => vec![format!("{before}{after}")],
-            1 => vec![format!("{before}{}{}", new_lines[0], after)],
-            _ => {
-                let mut result = Vec::new();
-                result.push(format!("{before}{}", new_lines[0]));
-                result.extend_from_slice(&new_lines[1..new_lines.len() - 1]);
-                result.push(format!("{}{}", new_lines.last().unwrap(), after));
-                result
-            }
+    /// Replaces text between `start` and `end`, clamped like [`Self::clamp`], with
+    /// `new_text`, and returns the edit for the tree. `None` leaves the text as it is when
+    /// a position is not in it or `end` comes before `start`.
+    pub fn apply_change(
+        &mut self,
+        start: &Position,
+        end: &Position,
+        new_text: &str,
+    ) -> Option<InputEdit> {
+        let (start, end) = (self.clamp(*start)?, self.clamp(*end)?);
+        if (end.line, end.character) < (start.line, start.character) {
+            return None;
+        }
+        let start_byte = self.position_to_offset(&start)?;
+        let old_end_byte = self.position_to_offset(&end)?;
+
+        if self.ends_with_newline || self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+        let mut lines = split(new_text);
+        let last = lines.len() - 1;
+        let new_end_position = Point {
+            row: start.line + last,
+            column: lines[last].len() + if last == 0 { start.character } else { 0 },
         };
+        lines[0].insert_str(0, &self.lines[start.line][..start.character]);
+        lines[last].push_str(&self.lines[end.line][end.character..]);
+        self.lines.splice(start.line..=end.line, lines);
+        self.trim_end();
+        // Not `new_text.len()`, the `\r` of its line breaks are gone.
+        let new_end_byte = self.position_to_offset(&Position::from_point(new_end_position))?;
+
+        Some(InputEdit {
+            start_byte,
+            old_end_byte,
+            new_end_byte,
+            start_position: start.point(),
+            old_end_position: end.point(),
+            new_end_position,
+        })
+    }
+}
+
+/// Lines of `text` split at `\n`, without the `\r` of `\r\n`. The last one is empty when
+/// the text ends with a line break.
+fn split(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = text.split('\n').map(str::to_string).collect();
+    let last = lines.len() - 1;
+    for line in &mut lines[..last] {
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PositionEncoding::{Utf16, Utf8};
+
+    fn line_list(text: &str) -> LineList {
+        let mut line_list = LineList::new();
+        line_list.set_text(text);
+        line_list
+    }
+
+    #[test]
+    fn converts_columns_to_utf16() {
+        let line_list = line_list("[Юнит]\nk='h😀',x");
+        assert_eq!(line_list.character(0, 9, Utf16), 5);
+        assert_eq!(line_list.character(1, 8, Utf16), 6);
+        assert_eq!(line_list.character(1, 10, Utf16), 8);
+        assert_eq!(line_list.character(1, 12, Utf16), 10);
+        assert_eq!(line_list.character(0, 9, Utf8), 9);
+    }
+
+    #[test]
+    fn converts_utf16_back_to_columns() {
+        let line_list = line_list("[Юнит]\nk='h😀',x");
+        for column in [0, 1, 3, 9, 10] {
+            let character = line_list.character(0, column, Utf16);
+            assert_eq!(line_list.column(0, character, Utf16), column);
+        }
+        assert_eq!(line_list.column(1, 6, Utf16), 8);
+        assert_eq!(line_list.column(1, 10, Utf16), 12);
+        assert_eq!(line_list.column(5, 3, Utf16), 3);
+    }
+
+    fn at(line: usize, character: usize) -> Position {
+        Position { line, character }
+    }
+
+    /// Applies the change and checks the edit against the text it leaves.
+    fn change(line_list: &mut LineList, start: Position, end: Position, text: &str) {
+        let before = line_list.to_text();
+        let edit = line_list.apply_change(&start, &end, text).unwrap();
+        let after = line_list.to_text();
+        assert_eq!(before[..edit.start_byte], after[..edit.start_byte]);
+        assert_eq!(before[edit.old_end_byte..], after[edit.new_end_byte..]);
+        assert_eq!(
+            line_list.point_from_offset(edit.new_end_byte),
+            edit.new_end_position
+        );
+    }
+
+    #[test]
+    fn keeps_final_line_break() {
+        let line_list = line_list("[A]\r\nk=1\r\n");
+        assert_eq!(line_list.lines, ["[A]", "k=1"]);
+        assert!(line_list.ends_with_newline);
+        assert_eq!(line_list.to_text(), "[A]\nk=1\n");
+        assert_eq!(line_list.position_to_offset(&at(2, 0)), Some(8));
+        assert_eq!(line_list.position_to_offset(&at(3, 0)), None);
+    }
+
+    #[test]
+    fn types_after_final_line_break() {
+        let mut line_list = line_list("[A]\nk=1\n");
+        change(&mut line_list, at(2, 0), at(2, 0), "x");
+        assert_eq!(line_list.to_text(), "[A]\nk=1\nx");
+        assert!(!line_list.ends_with_newline);
+    }
+
+    #[test]
+    fn types_on_new_line() {
+        let mut line_list = line_list("[A]\nk=1\n");
+        change(&mut line_list, at(0, 3), at(0, 3), "\n");
+        assert_eq!(line_list.lines, ["[A]", "", "k=1"]);
+        change(&mut line_list, at(1, 0), at(1, 0), "a=2");
+        assert_eq!(line_list.to_text(), "[A]\na=2\nk=1\n");
+        change(&mut line_list, at(2, 3), at(3, 0), "");
+        assert_eq!(line_list.to_text(), "[A]\na=2\nk=1");
+    }
+
+    #[test]
+    fn types_into_empty_text() {
+        let mut line_list = line_list("");
+        assert_eq!(line_list.lines, [""]);
+        change(&mut line_list, at(0, 0), at(0, 0), "[A]\r\n");
+        assert_eq!(line_list.to_text(), "[A]\n");
+        change(&mut line_list, at(0, 0), at(1, 0), "");
+        assert_eq!(line_list.to_text(), "");
+    }
+
+    #[test]
+    fn clamps_columns() {
+        let mut line_list = line_list("[Юнит]\nk=1");
+        assert_eq!(line_list.clamp(at(0, 2)), Some(at(0, 1)));
+        assert_eq!(line_list.clamp(at(1, 40)), Some(at(1, 3)));
+        change(&mut line_list, at(1, 40), at(1, 50), "0");
+        assert_eq!(line_list.to_text(), "[Юнит]\nk=10");
+    }
 
-        self.lines.splice(start_line..=end_line, replacement);
+    #[test]
+    fn refuses_changes_outside_text() {
+        let mut line_list = line_list("[A]\nk=1");
+        assert!(line_list.apply_change(&at(2, 0), &at(2, 0), "x").is_none());
+        assert!(line_list.apply_change(&at(1, 2), &at(0, 1), "x").is_none());
+        assert_eq!(line_list.to_text(), "[A]\nk=1");
     }
 }
@@ -0,0 +1,13 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Locking that outlives a panicked handler: the guard of a poisoned mutex is taken
+/// as is, callers that care mark what the handler was working on as stale.
+pub trait Lock<T> {
+    fn locked(&self) -> MutexGuard<'_, T>;
+}
+
+impl<T> Lock<T> for Mutex<T> {
+    fn locked(&self) -> MutexGuard<'_, T> {
+        self.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
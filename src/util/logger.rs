@@ -0,0 +1,295 @@
+use crate::lsp::set_trace::TraceValue;
+use crate::util::lock::Lock;
+use crate::util::settings::Settings;
+use log::kv::{self, VisitSource};
+use log::{LevelFilter, Log, Metadata, Record};
+use once_cell::sync::Lazy;
+use serde_json::{json, Map, Value};
+use std::cell::RefCell;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Log file above this is moved to `<file>.1`, older ones shift up.
+pub const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Rotated files kept next to the current one.
+pub const LOG_BACKUPS: usize = 3;
+
+static LOGGER: Lazy<Logger> = Lazy::new(|| Logger {
+    stderr: env_logger::Builder::from_default_env().build(),
+    file: Mutex::new(None),
+    level: Mutex::new(LevelFilter::Info),
+});
+
+thread_local! {
+    /// `id` and `method` of the call being handled on this thread.
+    static REQUEST: RefCell<Option<(Option<Value>, String)>> = const { RefCell::new(None) };
+}
+
+/// `env_logger` on stderr as before, plus JSON lines in the optional log file.
+struct Logger {
+    stderr: env_logger::Logger,
+    file: Mutex<Option<LogFile>>,
+    level: Mutex<LevelFilter>,
+}
+
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+impl LogFile {
+    fn open(path: &Path) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            size: file.metadata()?.len(),
+            file,
+        })
+    }
+
+    fn write(&mut self, line: &str) {
+        if self.size > 0 && self.size + line.len() as u64 > MAX_LOG_SIZE {
+            self.rotate();
+        }
+        match self.file.write_all(line.as_bytes()) {
+            Ok(()) => self.size += line.len() as u64,
+            // Logging about the log would come right back here.
+            Err(err) => eprintln!("Can't write log {:?}: {}", self.path, err),
+        }
+    }
+
+    /// `<file>.2` -> `<file>.3`, `<file>.1` -> `<file>.2`, `<file>` -> `<file>.1`.
+    fn rotate(&mut self) {
+        let backup = |n: usize| PathBuf::from(format!("{}.{n}", self.path.display()));
+        for n in (1..LOG_BACKUPS).rev() {
+            let _ = fs::rename(backup(n), backup(n + 1));
+        }
+        let _ = fs::rename(&self.path, backup(1));
+
+        match Self::open(&self.path) {
+            Ok(file) => *self = file,
+            Err(err) => eprintln!("Can't reopen log {:?}: {}", self.path, err),
+        }
+    }
+}
+
+/// Collects `key = value` pairs of a record into JSON.
+struct Fields(Map<String, Value>);
+
+impl<'kvs> VisitSource<'kvs> for Fields {
+    fn visit_pair(&mut self, key: kv::Key<'kvs>, value: kv::Value<'kvs>) -> Result<(), kv::Error> {
+        let value = if let Some(n) = value.to_u64() {
+            json!(n)
+        } else if let Some(n) = value.to_i64() {
+            json!(n)
+        } else if let Some(n) = value.to_f64() {
+            json!(n)
+        } else if let Some(b) = value.to_bool() {
+            json!(b)
+        } else {
+            json!(value.to_string())
+        };
+        self.0.insert(key.to_string(), value);
+        Ok(())
+    }
+}
+
+impl Logger {
+    fn file_enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= *self.level.locked() && self.file.locked().is_some()
+    }
+
+    /// Lets records through `log` macros if any of the outputs wants them.
+    fn update_max_level(&self) {
+        let file = match self.file.locked().is_some() {
+            true => *self.level.locked(),
+            false => LevelFilter::Off,
+        };
+        log::set_max_level(self.stderr.filter().max(file));
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.stderr.enabled(metadata) || self.file_enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.stderr.matches(record) {
+            self.stderr.log(record);
+        }
+        if !self.file_enabled(record.metadata()) {
+            return;
+        }
+
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64);
+        let mut fields = Fields(Map::new());
+        let _ = record.key_values().visit(&mut fields);
+
+        let mut line = json!({
+            "time": time,
+            "level": record.level().as_str(),
+            "target": record.target(),
+            "message": record.args().to_string(),
+        });
+        REQUEST.with_borrow(|request| {
+            if let Some((id, method)) = request {
+                if let Some(id) = id {
+                    line["id"] = id.clone();
+                }
+                line["method"] = json!(method);
+            }
+        });
+        if !fields.0.is_empty() {
+            line["fields"] = Value::Object(fields.0);
+        }
+
+        if let Some(file) = self.file.locked().as_mut() {
+            file.write(&format!("{line}\n"));
+        }
+    }
+
+    fn flush(&self) {
+        self.stderr.flush();
+        if let Some(file) = self.file.locked().as_mut() {
+            let _ = file.file.flush();
+        }
+    }
+}
+
+/// Installs the logger, stderr only until `set_file`.
+pub fn init() {
+    if log::set_logger(&*LOGGER).is_ok() {
+        LOGGER.update_max_level();
+    }
+}
+
+/// Starts writing JSON lines to `path`, `None` stops.
+pub fn set_file(path: Option<&Path>) {
+    let file = path.and_then(|path| match LogFile::open(path) {
+        Ok(file) => Some(file),
+        Err(err) => {
+            eprintln!("Can't open log {:?}: {}", path, err);
+            None
+        }
+    });
+    *LOGGER.file.locked() = file;
+    LOGGER.update_max_level();
+}
+
+/// Level of the log file.
+pub fn set_level(level: LevelFilter) {
+    *LOGGER.level.locked() = level;
+    LOGGER.update_max_level();
+}
+
+/// Applies `logFile` and `logLevel`, keeping what they don't mention.
+pub fn configure(settings: &Settings) {
+    if let Some(path) = &settings.log_file {
+        let current = LOGGER.file.locked().as_ref().map(|f| f.path.clone());
+        if current.as_ref() != Some(path) {
+            set_file(Some(path));
+        }
+    }
+    if let Some(level) = &settings.log_level {
+        match level.parse() {
+            Ok(level) => set_level(level),
+            Err(_) => log::warn!("Unknown log level: {level:?}"),
+        }
+    }
+}
+
+/// `$/setTrace`: `messages` and `verbose` open up the file log, `off` returns to `logLevel`.
+pub fn set_trace(value: &TraceValue, settings: &Settings) {
+    let level = match value {
+        TraceValue::Off => settings
+            .log_level
+            .as_deref()
+            .and_then(|level| level.parse().ok())
+            .unwrap_or(LevelFilter::Info),
+        TraceValue::Messages => LevelFilter::Debug,
+        TraceValue::Verbose => LevelFilter::Trace,
+    };
+    set_level(level);
+}
+
+/// Runs `f` as the handler of one call: log lines inside carry its `id` and `method`,
+/// and its duration is logged after.
+pub fn request<T>(id: Option<&Value>, method: &str, f: impl FnOnce() -> T) -> T {
+    let started = Instant::now();
+    REQUEST.set(Some((id.cloned(), method.to_string())));
+
+    let result = f();
+
+    let ms = (started.elapsed().as_secs_f64() * 1e6).round() / 1e3;
+    // Notifications like `didChange` come with every keystroke.
+    let level = match id {
+        Some(_) => log::Level::Info,
+        None => log::Level::Debug,
+    };
+    log::log!(target: "request", level, ms = ms; "{method} handled in {ms} ms");
+    REQUEST.set(None);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const MIB: usize = 1024 * 1024;
+
+    /// First byte of the file, the generation of lines it holds.
+    fn generation(path: &Path) -> Option<u8> {
+        fs::read(path).ok().map(|bytes| bytes[0])
+    }
+
+    #[test]
+    fn rotates_at_limit_keeping_backups() {
+        let dir = env::temp_dir().join(format!("jass-log-{}", std::process::id()));
+        let path = dir.join("server.log");
+        let backup = |n: usize| dir.join(format!("server.log.{n}"));
+        let mut log = LogFile::open(&path).unwrap();
+
+        // Ten lines of 1 MiB fill a file exactly, the next one goes to a new file.
+        for generation in b'a'..=b'e' {
+            let line = format!("{}\n", (generation as char).to_string().repeat(MIB - 1));
+            for _ in 0..MAX_LOG_SIZE as usize / MIB {
+                log.write(&line);
+            }
+        }
+
+        assert_eq!(fs::metadata(&path).unwrap().len(), MAX_LOG_SIZE);
+        assert_eq!(generation(&path), Some(b'e'));
+        assert_eq!(generation(&backup(1)), Some(b'd'));
+        assert_eq!(generation(&backup(2)), Some(b'c'));
+        assert_eq!(generation(&backup(LOG_BACKUPS)), Some(b'b'));
+        assert_eq!(generation(&backup(LOG_BACKUPS + 1)), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn keeps_oversized_line_in_empty_file() {
+        let dir = env::temp_dir().join(format!("jass-log-big-{}", std::process::id()));
+        let path = dir.join("server.log");
+        let mut log = LogFile::open(&path).unwrap();
+
+        log.write(&"x".repeat(MAX_LOG_SIZE as usize + 1));
+        assert_eq!(log.size, MAX_LOG_SIZE + 1);
+        assert!(!dir.join("server.log.1").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
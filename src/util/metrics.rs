@@ -0,0 +1,176 @@
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub static METRICS: Lazy<Mutex<Metrics>> = Lazy::new(|| Mutex::new(Metrics::default()));
+
+/// Upper bounds of histogram buckets in milliseconds, the last bucket takes the rest.
+pub const BUCKETS_MS: [f64; 12] = [
+    0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0, 1024.0,
+];
+
+/// Timings gathered since the server started.
+#[derive(Debug)]
+pub struct Metrics {
+    started: Instant,
+    /// Handling time by LSP method.
+    pub requests: BTreeMap<String, Histogram>,
+    /// Parse time by language id.
+    pub parses: BTreeMap<&'static str, Histogram>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            requests: BTreeMap::new(),
+            parses: BTreeMap::new(),
+        }
+    }
+}
+
+impl Metrics {
+    pub fn uptime(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    pub fn request(&mut self, method: &str, duration: Duration) {
+        self.requests
+            .entry(method.to_string())
+            .or_default()
+            .record(duration);
+    }
+
+    pub fn parse(&mut self, lng: &'static str, duration: Duration) {
+        self.parses.entry(lng).or_default().record(duration);
+    }
+}
+
+/// Durations counted in `BUCKETS_MS`, with percentiles read off the bucket bounds.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Histogram {
+    pub count: u64,
+    #[serde(serialize_with = "round")]
+    pub total_ms: f64,
+    #[serde(serialize_with = "round")]
+    pub max_ms: f64,
+    pub buckets: [u64; BUCKETS_MS.len() + 1],
+}
+
+impl Histogram {
+    pub fn record(&mut self, duration: Duration) {
+        let ms = duration.as_secs_f64() * 1000.0;
+        let bucket = BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(BUCKETS_MS.len());
+        self.buckets[bucket] += 1;
+        self.count += 1;
+        self.total_ms += ms;
+        self.max_ms = self.max_ms.max(ms);
+    }
+
+    /// Bound of the bucket holding the `p` share of samples, capped by `max_ms`.
+    pub fn percentile(&self, p: f64) -> f64 {
+        let target = (self.count as f64 * p).ceil() as u64;
+        let mut seen = 0;
+        for (i, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= target.max(1) {
+                return BUCKETS_MS
+                    .get(i)
+                    .map_or(self.max_ms, |b| b.min(self.max_ms));
+            }
+        }
+        self.max_ms
+    }
+}
+
+/// Histogram with `p50Ms` and `p95Ms` next to the raw counts, as `jass/serverStatus` shows it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistogramSummary {
+    #[serde(flatten)]
+    pub histogram: Histogram,
+    #[serde(serialize_with = "round")]
+    pub mean_ms: f64,
+    #[serde(serialize_with = "round")]
+    pub p50_ms: f64,
+    #[serde(serialize_with = "round")]
+    pub p95_ms: f64,
+}
+
+impl From<&Histogram> for HistogramSummary {
+    fn from(histogram: &Histogram) -> Self {
+        Self {
+            histogram: histogram.clone(),
+            mean_ms: histogram.total_ms / histogram.count.max(1) as f64,
+            p50_ms: histogram.percentile(0.5),
+            p95_ms: histogram.percentile(0.95),
+        }
+    }
+}
+
+fn round<S: serde::Serializer>(ms: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64((ms * 1000.0).round() / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ms(ms: f64) -> Duration {
+        Duration::from_secs_f64(ms / 1000.0)
+    }
+
+    #[test]
+    fn counts_durations_into_buckets_by_upper_bound() {
+        let mut histogram = Histogram::default();
+        for duration in [0.1, 0.5, 0.6, 3.0, 1024.0, 5000.0] {
+            histogram.record(ms(duration));
+        }
+
+        assert_eq!(histogram.count, 6);
+        assert_eq!(histogram.buckets, [2, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1]);
+        assert!((histogram.max_ms - 5000.0).abs() < 1e-6);
+        assert!((histogram.total_ms - 6028.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reads_percentiles_off_bucket_bounds() {
+        let mut histogram = Histogram::default();
+        assert_eq!(histogram.percentile(0.5), 0.0);
+
+        for _ in 0..90 {
+            histogram.record(ms(3.0));
+        }
+        for _ in 0..10 {
+            histogram.record(ms(100.0));
+        }
+        assert_eq!(histogram.percentile(0.5), 4.0);
+        assert_eq!(histogram.percentile(0.9), 4.0);
+        // Bound of 100 ms is 128, but nothing took longer than 100.
+        assert!((histogram.percentile(0.95) - 100.0).abs() < 1e-6);
+
+        histogram.record(ms(3000.0));
+        assert!((histogram.percentile(1.0) - 3000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn summarizes_with_rounded_milliseconds() {
+        let mut histogram = Histogram::default();
+        histogram.record(ms(1.0 / 3.0));
+        histogram.record(ms(1.0));
+
+        let summary = serde_json::to_value(HistogramSummary::from(&histogram)).unwrap();
+        assert_eq!(summary["count"], 2);
+        assert_eq!(summary["totalMs"], json!(1.333));
+        assert_eq!(summary["meanMs"], json!(0.667));
+        assert_eq!(summary["p50Ms"], json!(0.5));
+        assert_eq!(summary["p95Ms"], json!(1.0));
+    }
+}
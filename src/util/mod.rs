@@ -1,2 +1,18 @@
+#[cfg(feature = "server")]
+pub mod client;
+#[cfg(feature = "server")]
+pub mod debounce;
+pub mod encoding;
+#[cfg(feature = "server")]
+pub mod isolate;
 pub mod line_list;
+pub mod lock;
+#[cfg(feature = "server")]
+pub mod logger;
+#[cfg(feature = "server")]
+pub mod metrics;
+#[cfg(feature = "server")]
+pub mod progress;
+pub mod settings;
 pub mod uri_map;
+pub mod workspace;
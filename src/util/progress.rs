@@ -0,0 +1,212 @@
+use crate::lsp::progress::{
+    ProgressParams, WorkDoneProgressBegin, WorkDoneProgressCreateParams, WorkDoneProgressEnd,
+    WorkDoneProgressReport,
+};
+use crate::lsp::rpc::{call, notify};
+use crate::util::client::CLIENT;
+use crate::util::lock::Lock;
+use log::warn;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Cancel flags of running tasks, keyed by serialized token.
+static CANCELLED: Lazy<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static TOKEN: AtomicU64 = AtomicU64::new(1);
+
+/// How long a task waits for the client to create its progress token.
+const CREATE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Work-done progress of one long task, shown by the client as begin/report/end.
+/// Silent when the client can't show progress, `end` is sent on drop.
+pub struct Progress {
+    token: Option<Value>,
+    cancelled: Arc<AtomicBool>,
+    percentage: u32,
+    message: Option<String>,
+    /// Where `$/progress` params go, the client but in tests.
+    sink: fn(ProgressParams<Value>),
+}
+
+impl Progress {
+    /// Server-initiated progress, the token is created with `window/workDoneProgress/create`
+    /// before anything is sent with it. Silent when the client refuses or doesn't answer.
+    /// Blocks until the answer, so never from the main loop.
+    pub fn begin(title: &str) -> Self {
+        if !CLIENT.locked().work_done_progress {
+            return Self::start(None, title, to_client);
+        }
+        let token = Value::from(format!("jass/{}", TOKEN.fetch_add(1, Ordering::Relaxed)));
+        let created = call(
+            "window/workDoneProgress/create",
+            WorkDoneProgressCreateParams {
+                token: token.clone(),
+            },
+            CREATE_TIMEOUT,
+        );
+        match created {
+            Ok(_) => Self::start(Some(token), title, to_client),
+            Err(err) => {
+                warn!("No progress for {title}: {err}");
+                Self::start(None, title, to_client)
+            }
+        }
+    }
+
+    /// Client-initiated progress with `workDoneToken` of the request, if it sent one.
+    pub fn with_token(token: Option<Value>, title: &str) -> Self {
+        match token {
+            Some(token) => Self::start(Some(token), title, to_client),
+            None => Self::begin(title),
+        }
+    }
+
+    fn start(token: Option<Value>, title: &str, sink: fn(ProgressParams<Value>)) -> Self {
+        let progress = Self {
+            token,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            percentage: 0,
+            message: None,
+            sink,
+        };
+        if let Some(token) = &progress.token {
+            CANCELLED
+                .locked()
+                .insert(token.to_string(), progress.cancelled.clone());
+        }
+        progress.send(WorkDoneProgressBegin {
+            kind: "begin".into(),
+            title: title.into(),
+            cancellable: true,
+            message: None,
+            percentage: Some(0),
+        });
+        progress
+    }
+
+    /// Reports `done` of `total`, only when the whole percent changes.
+    pub fn report(&mut self, done: usize, total: usize) {
+        let percentage = (done * 100).checked_div(total).unwrap_or(100) as u32;
+        if percentage == self.percentage {
+            return;
+        }
+        self.percentage = percentage;
+
+        self.send(WorkDoneProgressReport {
+            kind: "report".into(),
+            message: Some(format!("{done}/{total}")),
+            percentage: Some(percentage),
+        });
+    }
+
+    /// The user pressed cancel on the progress notification.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn end(mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    fn send<T: Serialize>(&self, value: T) {
+        if let Some(token) = &self.token {
+            (self.sink)(ProgressParams {
+                token: token.clone(),
+                value: serde_json::to_value(value).unwrap(),
+            });
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        let Some(token) = &self.token else {
+            return;
+        };
+        CANCELLED.locked().remove(&token.to_string());
+        let message = self.message.take();
+        self.send(WorkDoneProgressEnd {
+            kind: "end".into(),
+            message,
+        });
+    }
+}
+
+fn to_client(params: ProgressParams<Value>) {
+    notify("$/progress", params);
+}
+
+/// Handles `window/workDoneProgress/cancel`, the task stops at its next check.
+pub fn cancel(token: &Value) {
+    if let Some(cancelled) = CANCELLED.locked().get(&token.to_string()) {
+        cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SENT: RefCell<Vec<Value>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(params: ProgressParams<Value>) {
+        SENT.with_borrow_mut(|sent| sent.push(serde_json::to_value(params).unwrap()));
+    }
+
+    fn sent() -> Vec<Value> {
+        SENT.take()
+    }
+
+    #[test]
+    fn reports_whole_percents_then_ends() {
+        let mut progress = Progress::start(Some(json!("t1")), "Indexing", record);
+        for done in 0..=4 {
+            progress.report(done, 400);
+        }
+        progress.report(200, 400);
+        progress.end("done");
+
+        assert_eq!(
+            sent(),
+            [
+                json!({"token": "t1", "value": {"kind": "begin", "title": "Indexing", "cancellable": true, "percentage": 0}}),
+                json!({"token": "t1", "value": {"kind": "report", "message": "4/400", "percentage": 1}}),
+                json!({"token": "t1", "value": {"kind": "report", "message": "200/400", "percentage": 50}}),
+                json!({"token": "t1", "value": {"kind": "end", "message": "done"}}),
+            ]
+        );
+    }
+
+    #[test]
+    fn ends_on_drop_and_forgets_cancel_flag() {
+        let token = json!("t2");
+        {
+            let progress = Progress::start(Some(token.clone()), "Checking", record);
+            cancel(&token);
+            assert!(progress.is_cancelled());
+        }
+        assert!(!CANCELLED.locked().contains_key(&token.to_string()));
+
+        let sent = sent();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1], json!({"token": "t2", "value": {"kind": "end"}}));
+    }
+
+    #[test]
+    fn stays_silent_without_token() {
+        let mut progress = Progress::start(None, "Indexing", record);
+        progress.report(1, 2);
+        progress.end("done");
+        assert!(sent().is_empty());
+    }
+}
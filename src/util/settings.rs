@@ -0,0 +1,57 @@
+use log::warn;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+pub static SETTINGS: Lazy<Mutex<Settings>> = Lazy::new(|| Mutex::new(Settings::default()));
+
+/// User settings, from `initializationOptions` and the `jass` section of
+/// `workspace/didChangeConfiguration`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    /// JSON lines log written next to stderr.
+    pub log_file: Option<PathBuf>,
+    /// `off`, `error`, `warn`, `info`, `debug` or `trace`.
+    pub log_level: Option<String>,
+    pub format: FormatSettings,
+    pub bni: BniSettings,
+}
+
+/// Formatter choices beyond the fixed rules, `jass.format.*`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FormatSettings {
+    /// Pads keys of a section to the longest one so the `=` line up.
+    pub align_keys: bool,
+    /// Orders keys of a section by name, comments move with the key below them.
+    pub sort_keys: bool,
+    /// `key = value` instead of `key=value`.
+    pub space_around_equals: bool,
+}
+
+/// BNI specifics, `jass.bni.*`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BniSettings {
+    /// Keys whose values name sections, `Key` or `Section.Key` with `*` and `?` wildcards,
+    /// for references and rename. None by default, so rename only touches headers until
+    /// the user opts in.
+    pub reference_keys: Vec<String>,
+}
+
+impl Settings {
+    /// Accepts both `{ "jass": { .. } }` and the bare section.
+    pub fn from_value(value: &Value) -> Self {
+        let section = value.get("jass").unwrap_or(value);
+        if section.is_null() {
+            return Self::default();
+        }
+        serde_json::from_value(section.clone()).unwrap_or_else(|err| {
+            warn!("Invalid settings: {}", err);
+            Self::default()
+        })
+    }
+}
@@ -1,20 +1,26 @@
-use crate::lsp::semantic_hub::SemanticTokenHub;
+use crate::lsp::diagnostic::Diagnostic;
+use crate::lsp::semantic_hub::{SemanticTokenHub, Token};
+use crate::util::encoding::Encoding;
 use crate::util::line_list::LineList;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 use tree_sitter::Tree;
 use url::Url;
 
 pub static URI_MAP: Lazy<Mutex<UriMap>> = Lazy::new(|| Mutex::new(UriMap::new()));
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct UriMap {
     pub semantic: HashMap<Url, SemanticTokenHub>,
     pub tree: HashMap<Url, Option<Tree>>,
     pub lng: HashMap<Url, Option<String>>,
     pub line_list: HashMap<Url, LineList>,
-    //pub diagnostics: HashMap<Url, Vec<String>>,
+    pub encoding: HashMap<Url, Encoding>,
+    pub diagnostics: HashMap<Url, Vec<Diagnostic>>,
+    /// Documents whose text we no longer trust, after a handler panicked on them.
+    pub stale: HashSet<Url>,
     //pub symbols: HashMap<Url, Vec<String>>,
     //pub comments: HashMap<Url, Vec<String>>,
 }
@@ -26,24 +32,21 @@ impl UriMap {
             tree: HashMap::new(),
             lng: HashMap::new(),
             line_list: HashMap::new(),
-            //diagnostics: HashMap::new(),
+            encoding: HashMap::new(),
+            diagnostics: HashMap::new(),
+            stale: HashSet::new(),
             //symbols: HashMap::new(),
             //comments: HashMap::new(),
         }
     }
 
     pub fn entry(&mut self, url: &Url) -> UriMapEntry<'_> {
-        let semantic = self
-            .semantic
-            .entry(url.clone())
-            .or_insert_with(SemanticTokenHub::new);
+        let semantic = self.semantic.entry(url.clone()).or_default();
         let tree = self.tree.entry(url.clone()).or_insert(None);
         let lng = self.lng.entry(url.clone()).or_insert(None);
-        let line_list = self
-            .line_list
-            .entry(url.clone())
-            .or_insert_with(LineList::new);
-        //let diagnostics = self.diagnostics.entry(url.clone()).or_insert_with(Vec::new);
+        let line_list = self.line_list.entry(url.clone()).or_default();
+        let encoding = self.encoding.entry(url.clone()).or_default();
+        let diagnostics = self.diagnostics.entry(url.clone()).or_default();
         //let symbols = self.symbols.entry(url.clone()).or_insert_with(Vec::new);
         //let comments = self.comments.entry(url.clone()).or_insert_with(Vec::new);
 
@@ -52,19 +55,89 @@ impl UriMap {
             tree,
             lng,
             line_list,
-            //diagnostics,
+            encoding,
+            diagnostics,
             //symbols,
             //comments,
         }
     }
+
+    pub fn memory(&self) -> MemoryEstimate {
+        let text = self
+            .line_list
+            .values()
+            .flat_map(|l| &l.lines)
+            .map(|line| line.capacity() + size_of::<String>())
+            .sum();
+        let trees = self
+            .tree
+            .values()
+            .flatten()
+            .map(|tree| tree.root_node().descendant_count() * TREE_NODE_BYTES)
+            .sum();
+        let tokens = self
+            .semantic
+            .values()
+            .map(|hub| hub.token_count() * size_of::<Token>())
+            .sum();
+        let diagnostics = self
+            .diagnostics
+            .values()
+            .flatten()
+            .map(|d| size_of::<Diagnostic>() + d.message.capacity())
+            .sum();
+
+        MemoryEstimate {
+            text,
+            trees,
+            tokens,
+            diagnostics,
+            total: text + trees + tokens + diagnostics,
+        }
+    }
+
+    /// Forgets the tree and tokens of a document whose text went out of sync, until the
+    /// client sends it whole again.
+    pub fn invalidate(&mut self, url: &Url) {
+        if let Some(tree) = self.tree.get_mut(url) {
+            tree.take();
+        }
+        self.semantic.remove(url);
+        self.stale.insert(url.clone());
+    }
+
+    pub fn remove(&mut self, url: &Url) {
+        self.semantic.remove(url);
+        self.tree.remove(url);
+        self.lng.remove(url);
+        self.line_list.remove(url);
+        self.encoding.remove(url);
+        self.diagnostics.remove(url);
+        self.stale.remove(url);
+    }
+}
+
+/// Rough heap usage of `UriMap` in bytes, to spot growth rather than to account exactly.
+#[derive(Debug, Default, Serialize)]
+pub struct MemoryEstimate {
+    pub text: usize,
+    pub trees: usize,
+    pub tokens: usize,
+    pub diagnostics: usize,
+    pub total: usize,
 }
 
+/// tree-sitter doesn't report its allocations, this is about what one node costs.
+const TREE_NODE_BYTES: usize = 48;
+
 pub struct UriMapEntry<'a> {
     pub semantic: &'a mut SemanticTokenHub,
     pub tree: &'a mut Option<Tree>,
     pub lng: &'a mut Option<String>,
     pub line_list: &'a mut LineList,
-    //pub diagnostics: &'a mut Vec<String>,
+    /// How the file is stored on disk, the client always sends UTF-8.
+    pub encoding: &'a mut Encoding,
+    pub diagnostics: &'a mut Vec<Diagnostic>,
     //pub symbols: &'a mut Vec<String>,
     //pub comments: &'a mut Vec<String>,
 }
@@ -0,0 +1,49 @@
+use crate::lsp::initialize::InitializeParams;
+use once_cell::sync::Lazy;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+pub static WORKSPACE: Lazy<Mutex<Workspace>> = Lazy::new(|| Mutex::new(Workspace::new()));
+
+#[derive(Debug, Default)]
+pub struct Workspace {
+    pub root: Option<PathBuf>,
+    /// Folders to index, the root alone when the client has no multi-root support.
+    pub folders: Vec<PathBuf>,
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            folders: Vec::new(),
+        }
+    }
+
+    /// `rootUri` wins over deprecated `rootPath`, `workspaceFolders` over both.
+    pub fn from_params(params: &InitializeParams) -> Self {
+        let root = params
+            .root_uri
+            .as_ref()
+            .and_then(|uri| uri.to_file_path().ok())
+            .or_else(|| params.root_path.as_ref().map(PathBuf::from));
+
+        let folders: Vec<PathBuf> = match &params.workspace_folders {
+            Some(folders) => folders
+                .iter()
+                .filter_map(|f| f.uri.to_file_path().ok())
+                .collect(),
+            None => root.iter().cloned().collect(),
+        };
+
+        Self {
+            root: root.or_else(|| folders.first().cloned()),
+            folders,
+        }
+    }
+
+    /// Path inside `<root>/.jass`, where the user keeps overrides for bundled resources.
+    pub fn override_path(&self, relative: &str) -> Option<PathBuf> {
+        Some(self.root.as_ref()?.join(".jass").join(relative))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::lng::{self, backend, Document, BACKENDS};
+use crate::lsp::semantic::{SemanticTokensLegend, ToCamelVec, TokenModifier, TokenType};
+use crate::util::settings::FormatSettings;
+use serde::Serialize;
+use url::Url;
+use wasm_bindgen::prelude::*;
+
+fn document(language: &str, text: &str) -> Result<Document, JsError> {
+    let backend =
+        backend(language).ok_or_else(|| JsError::new(&format!("Unknown language: {language}")))?;
+    Ok(Document::parse(backend, text))
+}
+
+fn json<T: Serialize>(value: &T) -> Result<String, JsError> {
+    serde_json::to_string(value).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Language ids `tokens`, `diagnostics`, `format` and `symbols` accept.
+#[wasm_bindgen]
+pub fn languages() -> Vec<String> {
+    BACKENDS.iter().map(|b| b.id.to_string()).collect()
+}
+
+/// `{ tokenTypes, tokenModifiers }` JSON, what `tokens` indexes into.
+#[wasm_bindgen]
+pub fn legend() -> Result<String, JsError> {
+    json(&SemanticTokensLegend {
+        token_types: <TokenType as ToCamelVec>::get_vec(),
+        token_modifiers: <TokenModifier as ToCamelVec>::get_vec(),
+    })
+}
+
+/// Semantic tokens in LSP relative encoding, five numbers per token.
+#[wasm_bindgen]
+pub fn tokens(language: &str, text: &str) -> Result<Vec<u32>, JsError> {
+    let data = document(language, text)?.semantic().data();
+    Ok(data.into_iter().map(|n| n as u32).collect())
+}
+
+/// JSON array of LSP `Diagnostic`, related information points to `uri`.
+#[wasm_bindgen]
+pub fn diagnostics(language: &str, text: &str, uri: Option<String>) -> Result<String, JsError> {
+    let uri = Url::parse(uri.as_deref().unwrap_or("untitled:document"))
+        .map_err(|err| JsError::new(&err.to_string()))?;
+    json(&document(language, text)?.diagnostics(&uri))
+}
+
+/// Formatted text, `settings` is JSON like the `jass.format` section.
+/// Text comes back as is for languages without a formatter.
+#[wasm_bindgen]
+pub fn format(language: &str, text: &str, settings: Option<String>) -> Result<String, JsError> {
+    let settings: FormatSettings = match settings {
+        Some(settings) => {
+            serde_json::from_str(&settings).map_err(|err| JsError::new(&err.to_string()))?
+        }
+        None => FormatSettings::default(),
+    };
+    Ok(match document(language, text)?.format(&settings) {
+        Some(lines) => lng::format::join(&lines, text),
+        None => text.to_string(),
+    })
+}
+
+/// JSON array of `{ name, kind, range, selection_range, container }`.
+#[wasm_bindgen]
+pub fn symbols(language: &str, text: &str) -> Result<String, JsError> {
+    json(&document(language, text)?.symbols())
+}
@@ -0,0 +1,64 @@
+/**
+ * @file BNI grammar for tree-sitter: `[Section]` headers, `key = value` lines with
+ * comma separated values, and whole-line `;` or `//` comments.
+ * @license MIT
+ */
+
+/// <reference types="tree-sitter-cli/dsl" />
+// @ts-check
+
+module.exports = grammar({
+  name: 'bni',
+
+  // Line breaks are not extras, they end headers, keys and comments.
+  extras: _ => [/[ \t\r\f\v]/],
+
+  rules: {
+    document: $ => seq(
+      repeat(seq(optional($._entry), $._newline)),
+      optional($._entry),
+    ),
+
+    _entry: $ => choice($.section, $.item, $.comment),
+
+    // `]` is optional so that an unterminated header is still a section.
+    section: $ => seq('[', optional(field('name', $.name)), optional(']')),
+
+    // Both sides are optional, `= x` and `key =` are for the language server to report.
+    item: $ => seq(
+      optional(field('key', $.key)),
+      '=',
+      optional(field('value', $.value)),
+    ),
+
+    value: $ => repeat1(choice(
+      $.number,
+      $.boolean,
+      $.rawcode,
+      $.string,
+      $.text,
+      ',',
+    )),
+
+    // Tokens are listed by priority, of two matches of equal length the earlier wins.
+    comment: _ => /(;|\/\/)([^\n]*[^ \t\r\f\v\n])?/,
+
+    key: _ => /[^ \t\r\f\v\n=\[;]([^\n=]*[^ \t\r\f\v\n=])?/,
+
+    name: _ => /[^ \t\r\f\v\n\]]([^\n\]]*[^ \t\r\f\v\n\]])?/,
+
+    number: _ => /[+-]?([0-9]+(\.[0-9]*)?|\.[0-9]+)([eE][+-]?[0-9]+)?/,
+
+    boolean: _ => /[tT][rR][uU][eE]|[fF][aA][lL][sS][eE]/,
+
+    // Object id: `'hfoo'`, or four letters and digits with at least one digit.
+    rawcode: _ => /'[0-9A-Za-z]{4}'|[0-9][0-9A-Za-z]{3}|[A-Za-z][0-9][0-9A-Za-z]{2}|[A-Za-z]{2}[0-9][0-9A-Za-z]|[A-Za-z]{3}[0-9]/,
+
+    string: _ => /"[^"\n]*"?/,
+
+    // Anything else up to the next comma, without the whitespace around it.
+    text: _ => /[^ \t\r\f\v\n,"]([^\n,]*[^ \t\r\f\v\n,])?/,
+
+    _newline: _ => /\n/,
+  },
+});
@@ -0,0 +1,3450 @@
+#include "tree_sitter/parser.h"
+
+#if defined(__GNUC__) || defined(__clang__)
+#pragma GCC diagnostic ignored "-Wmissing-field-initializers"
+#endif
+
+#define LANGUAGE_VERSION 14
+#define STATE_COUNT 35
+#define LARGE_STATE_COUNT 18
+#define SYMBOL_COUNT 21
+#define ALIAS_COUNT 0
+#define TOKEN_COUNT 14
+#define EXTERNAL_TOKEN_COUNT 0
+#define FIELD_COUNT 3
+#define MAX_ALIAS_SEQUENCE_LENGTH 3
+#define PRODUCTION_ID_COUNT 5
+
+enum ts_symbol_identifiers {
+  anon_sym_LBRACK = 1,
+  anon_sym_RBRACK = 2,
+  anon_sym_EQ = 3,
+  anon_sym_COMMA = 4,
+  sym_comment = 5,
+  sym_key = 6,
+  sym_name = 7,
+  sym_number = 8,
+  sym_boolean = 9,
+  sym_rawcode = 10,
+  sym_string = 11,
+  sym_text = 12,
+  sym__newline = 13,
+  sym_document = 14,
+  sym__entry = 15,
+  sym_section = 16,
+  sym_item = 17,
+  sym_value = 18,
+  aux_sym_document_repeat1 = 19,
+  aux_sym_value_repeat1 = 20,
+};
+
+static const char * const ts_symbol_names[] = {
+  [ts_builtin_sym_end] = "end",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
+  [anon_sym_EQ] = "=",
+  [anon_sym_COMMA] = ",",
+  [sym_comment] = "comment",
+  [sym_key] = "key",
+  [sym_name] = "name",
+  [sym_number] = "number",
+  [sym_boolean] = "boolean",
+  [sym_rawcode] = "rawcode",
+  [sym_string] = "string",
+  [sym_text] = "text",
+  [sym__newline] = "_newline",
+  [sym_document] = "document",
+  [sym__entry] = "_entry",
+  [sym_section] = "section",
+  [sym_item] = "item",
+  [sym_value] = "value",
+  [aux_sym_document_repeat1] = "document_repeat1",
+  [aux_sym_value_repeat1] = "value_repeat1",
+};
+
+static const TSSymbol ts_symbol_map[] = {
+  [ts_builtin_sym_end] = ts_builtin_sym_end,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [sym_comment] = sym_comment,
+  [sym_key] = sym_key,
+  [sym_name] = sym_name,
+  [sym_number] = sym_number,
+  [sym_boolean] = sym_boolean,
+  [sym_rawcode] = sym_rawcode,
+  [sym_string] = sym_string,
+  [sym_text] = sym_text,
+  [sym__newline] = sym__newline,
+  [sym_document] = sym_document,
+  [sym__entry] = sym__entry,
+  [sym_section] = sym_section,
+  [sym_item] = sym_item,
+  [sym_value] = sym_value,
+  [aux_sym_document_repeat1] = aux_sym_document_repeat1,
+  [aux_sym_value_repeat1] = aux_sym_value_repeat1,
+};
+
+static const TSSymbolMetadata ts_symbol_metadata[] = {
+  [ts_builtin_sym_end] = {
+    .visible = false,
+    .named = true,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_comment] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_key] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_name] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_number] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_boolean] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rawcode] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_string] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_text] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__newline] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_document] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__entry] = {
+    .visible = false,
+    .named = true,
+  },
+  [sym_section] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_item] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_value] = {
+    .visible = true,
+    .named = true,
+  },
+  [aux_sym_document_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_value_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
+
+enum ts_field_identifiers {
+  field_key = 1,
+  field_name = 2,
+  field_value = 3,
+};
+
+static const char * const ts_field_names[] = {
+  [0] = NULL,
+  [field_key] = "key",
+  [field_name] = "name",
+  [field_value] = "value",
+};
+
+static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
+  [1] = {.index = 0, .length = 1},
+  [2] = {.index = 1, .length = 2},
+  [3] = {.index = 3, .length = 1},
+  [4] = {.index = 4, .length = 1},
+};
+
+static const TSFieldMapEntry ts_field_map_entries[] = {
+  [0] =
+    {field_name, 1},
+  [1] =
+    {field_key, 0},
+    {field_value, 2},
+  [3] =
+    {field_key, 0},
+  [4] =
+    {field_value, 1},
+};
+
+static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
+  [0] = {0},
+};
+
+static const uint16_t ts_non_terminal_alias_map[] = {
+  0,
+};
+
+static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
+  [0] = 0,
+  [1] = 1,
+  [2] = 2,
+  [3] = 3,
+  [4] = 4,
+  [5] = 5,
+  [6] = 6,
+  [7] = 7,
+  [8] = 8,
+  [9] = 9,
+  [10] = 10,
+  [11] = 11,
+  [12] = 12,
+  [13] = 13,
+  [14] = 14,
+  [15] = 15,
+  [16] = 16,
+  [17] = 17,
+  [18] = 18,
+  [19] = 19,
+  [20] = 20,
+  [21] = 21,
+  [22] = 22,
+  [23] = 23,
+  [24] = 24,
+  [25] = 25,
+  [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
+  [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+};
+
+static bool ts_lex(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      if (eof) ADVANCE(102);
+      if (lookahead == '\n') ADVANCE(2);
+      if (lookahead == '"') ADVANCE(3);
+      if (lookahead == '\'') ADVANCE(4);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(5);
+      if (lookahead == ',') ADVANCE(6);
+      if (lookahead == '.') ADVANCE(7);
+      if (lookahead == '/') ADVANCE(8);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(9);
+      if (lookahead == ';') ADVANCE(10);
+      if (lookahead == '=') ADVANCE(11);
+      if (('A' <= lookahead && lookahead <= 'E') ||
+          ('G' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(12);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(13);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(14);
+      if (lookahead == '[') ADVANCE(15);
+      if (lookahead == ']') ADVANCE(16);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '+' || '9' < lookahead) &&
+          lookahead != ';' &&
+          lookahead != '=' &&
+          (lookahead < 'A' || '[' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(1);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(0);
+      END_STATE();
+    case 1:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 2:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 3:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(23);
+      if (lookahead == '"') ADVANCE(24);
+      if (lookahead == '=') ADVANCE(25);
+      if (lookahead == ']') ADVANCE(26);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(22);
+      END_STATE();
+    case 4:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(27);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 5:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(28);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 6:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(30);
+      if (lookahead == '=') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(32);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(19);
+      END_STATE();
+    case 7:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(33);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 8:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '/') ADVANCE(34);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '/' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 9:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(37);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(38);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 10:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(40);
+      if (lookahead == ',') ADVANCE(41);
+      if (lookahead == ']') ADVANCE(42);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(39);
+      END_STATE();
+    case 11:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(43);
+      if (lookahead == ',') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(20);
+      END_STATE();
+    case 12:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(46);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 13:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(47);
+      if (('B' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(46);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 14:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(46);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(48);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 15:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(43);
+      if (lookahead == ',') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(20);
+      END_STATE();
+    case 16:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(49);
+      if (lookahead == ',') ADVANCE(32);
+      if (lookahead == '=') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=') ADVANCE(21);
+      END_STATE();
+    case 17:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 18:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 19:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(30);
+      if (lookahead == '=') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(32);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(19);
+      END_STATE();
+    case 20:
+      ACCEPT_TOKEN(sym_name);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(43);
+      if (lookahead == ',') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(20);
+      END_STATE();
+    case 21:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(49);
+      if (lookahead == ',') ADVANCE(32);
+      if (lookahead == '=') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=') ADVANCE(21);
+      END_STATE();
+    case 22:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(23);
+      if (lookahead == '"') ADVANCE(24);
+      if (lookahead == '=') ADVANCE(25);
+      if (lookahead == ']') ADVANCE(26);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(22);
+      END_STATE();
+    case 23:
+      ACCEPT_TOKEN(sym_string);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(23);
+      if (lookahead == '"') ADVANCE(24);
+      if (lookahead == '=') ADVANCE(25);
+      if (lookahead == ']') ADVANCE(26);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(22);
+      END_STATE();
+    case 24:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(30);
+      if (lookahead == '=') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(32);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(19);
+      END_STATE();
+    case 25:
+      ACCEPT_TOKEN(sym_name);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(50);
+      if (lookahead == '"') ADVANCE(51);
+      if (lookahead == ']') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != ']') ADVANCE(25);
+      END_STATE();
+    case 26:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(53);
+      if (lookahead == '"') ADVANCE(54);
+      if (lookahead == '=') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '=') ADVANCE(26);
+      END_STATE();
+    case 27:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(55);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 28:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(33);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 29:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(56);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 30:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(30);
+      if (lookahead == '=') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(32);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(19);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(sym_name);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(58);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(31);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(59);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(32);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(60);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead == ',') ADVANCE(63);
+      if (lookahead == '=') ADVANCE(39);
+      if (lookahead == ']') ADVANCE(64);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(61);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(65);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(66);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(68);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(69);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(70);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(67);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(40);
+      if (lookahead == ',') ADVANCE(41);
+      if (lookahead == ']') ADVANCE(42);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(39);
+      END_STATE();
+    case 40:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(40);
+      if (lookahead == ',') ADVANCE(41);
+      if (lookahead == ']') ADVANCE(42);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(39);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(71);
+      if (lookahead == ']') ADVANCE(72);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(41);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(73);
+      if (lookahead == ',') ADVANCE(72);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(42);
+      END_STATE();
+    case 43:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(43);
+      if (lookahead == ',') ADVANCE(31);
+      if (lookahead == ']') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != ']') ADVANCE(20);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(74);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(44);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(75);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(76);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(77);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(76);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(77);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(78);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(76);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(77);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(79);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 49:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(49);
+      if (lookahead == ',') ADVANCE(32);
+      if (lookahead == '=') ADVANCE(44);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=') ADVANCE(21);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym_string);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(50);
+      if (lookahead == '"') ADVANCE(51);
+      if (lookahead == ']') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != ']') ADVANCE(25);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(sym_name);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(58);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(31);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(sym_string);
+      if (lookahead == '"') ADVANCE(80);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"') ADVANCE(52);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(sym_string);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(53);
+      if (lookahead == '"') ADVANCE(54);
+      if (lookahead == '=') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '=') ADVANCE(26);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(59);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(32);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(81);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(56);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(69);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(82);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 58:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(58);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(31);
+      END_STATE();
+    case 59:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(59);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(32);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(60);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead == ',') ADVANCE(63);
+      if (lookahead == '=') ADVANCE(39);
+      if (lookahead == ']') ADVANCE(64);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(61);
+      END_STATE();
+    case 62:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(62);
+      if (lookahead == ',') ADVANCE(63);
+      if (lookahead == '=') ADVANCE(39);
+      if (lookahead == ']') ADVANCE(64);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(61);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(83);
+      if (lookahead == '=') ADVANCE(41);
+      if (lookahead == ']') ADVANCE(84);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(63);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(85);
+      if (lookahead == ',') ADVANCE(84);
+      if (lookahead == '=') ADVANCE(42);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=') ADVANCE(64);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(65);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(86);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(87);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(88);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(87);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(69);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(89);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(87);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(82);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(90);
+      if (lookahead == '=') ADVANCE(20);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(87);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 71:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(71);
+      if (lookahead == ']') ADVANCE(72);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(41);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(91);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(72);
+      END_STATE();
+    case 73:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(73);
+      if (lookahead == ',') ADVANCE(72);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(42);
+      END_STATE();
+    case 74:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(74);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(44);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(92);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(93);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(94);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(94);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(95);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'S' &&
+          lookahead != ']' &&
+          lookahead != 's') ADVANCE(17);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(94);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(96);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(sym_string);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(97);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          lookahead != ']' &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(17);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(98);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 83:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(83);
+      if (lookahead == '=') ADVANCE(41);
+      if (lookahead == ']') ADVANCE(84);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(63);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(72);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(84);
+      END_STATE();
+    case 85:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(85);
+      if (lookahead == ',') ADVANCE(84);
+      if (lookahead == '=') ADVANCE(42);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=') ADVANCE(64);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '.') ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(56);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(57);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(69);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(82);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(98);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(98);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 91:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(91);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(72);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(100);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != 'E' &&
+          lookahead != ']' &&
+          lookahead != 'e') ADVANCE(17);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == '\'') ADVANCE(101);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '\'' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(98);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 99:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(99);
+      if (lookahead == '=') ADVANCE(72);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(84);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(18);
+      if (lookahead == ',') ADVANCE(19);
+      if (lookahead == '=') ADVANCE(20);
+      if (lookahead == ']') ADVANCE(21);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '=' &&
+          lookahead != ']') ADVANCE(17);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 103:
+      if (eof) ADVANCE(117);
+      if (lookahead == '\n') ADVANCE(105);
+      if (lookahead == '/') ADVANCE(106);
+      if (lookahead == ';') ADVANCE(107);
+      if (lookahead == '=') ADVANCE(108);
+      if (lookahead == '[') ADVANCE(109);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '/' &&
+          lookahead != ';' &&
+          lookahead != '=' &&
+          lookahead != '[') ADVANCE(104);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(103);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(111);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(110);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(111);
+      if (lookahead == '/') ADVANCE(112);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '/' &&
+          lookahead != '=') ADVANCE(110);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(114);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(113);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(sym_key);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(111);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(110);
+      END_STATE();
+    case 111:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(111);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(110);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(116);
+      if (lookahead == '=') ADVANCE(113);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(115);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(114);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(113);
+      END_STATE();
+    case 114:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(114);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ') ADVANCE(113);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(116);
+      if (lookahead == '=') ADVANCE(113);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(115);
+      END_STATE();
+    case 116:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(116);
+      if (lookahead == '=') ADVANCE(113);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '=') ADVANCE(115);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 118:
+      if (eof) ADVANCE(177);
+      if (lookahead == '\n') ADVANCE(120);
+      if (lookahead == '"') ADVANCE(121);
+      if (lookahead == '\'') ADVANCE(122);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(123);
+      if (lookahead == ',') ADVANCE(124);
+      if (lookahead == '.') ADVANCE(125);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
+      if (('A' <= lookahead && lookahead <= 'E') ||
+          ('G' <= lookahead && lookahead <= 'S') ||
+          ('U' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'e') ||
+          ('g' <= lookahead && lookahead <= 's') ||
+          ('u' <= lookahead && lookahead <= 'z')) ADVANCE(127);
+      if (lookahead == 'F' ||
+          lookahead == 'f') ADVANCE(128);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(129);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '\'' &&
+          (lookahead < '+' || '.' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(119);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(118);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(sym_string);
+      if (lookahead == '"') ADVANCE(133);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"') ADVANCE(132);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(134);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(135);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(136);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(139);
+      if (('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(140);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(141);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(142);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(143);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(142);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(144);
+      if (('B' <= lookahead && lookahead <= 'Z') ||
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(143);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(142);
+      if (('A' <= lookahead && lookahead <= 'Q') ||
+          ('S' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'q') ||
+          ('s' <= lookahead && lookahead <= 'z')) ADVANCE(143);
+      if (lookahead == 'R' ||
+          lookahead == 'r') ADVANCE(145);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 131:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(sym_string);
+      if (lookahead == '"') ADVANCE(133);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"') ADVANCE(132);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(sym_string);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(146);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(147);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(149);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(150);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(151);
+      if (('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(152);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(153);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(152);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(154);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(155);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(152);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(156);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(157);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(158);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(157);
+      if (('A' <= lookahead && lookahead <= 'K') ||
+          ('M' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'k') ||
+          ('m' <= lookahead && lookahead <= 'z')) ADVANCE(158);
+      if (lookahead == 'L' ||
+          lookahead == 'l') ADVANCE(159);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(157);
+      if (('A' <= lookahead && lookahead <= 'T') ||
+          ('V' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 't') ||
+          ('v' <= lookahead && lookahead <= 'z')) ADVANCE(158);
+      if (lookahead == 'U' ||
+          lookahead == 'u') ADVANCE(160);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(161);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(147);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(154);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(162);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(149);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(150);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(163);
+      if (('A' <= lookahead && lookahead <= 'D') ||
+          ('F' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'd') ||
+          ('f' <= lookahead && lookahead <= 'z')) ADVANCE(164);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(165);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(164);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(154);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(166);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(164);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(162);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(167);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(164);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(168);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(169);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(170);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(170);
+      if (lookahead == 'S' ||
+          lookahead == 's') ADVANCE(171);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'S' &&
+          lookahead != 's') ADVANCE(130);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(170);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(172);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(173);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          (lookahead < 'A' || 'Z' < lookahead) &&
+          (lookahead < 'a' || 'z' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(174);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '.') ADVANCE(138);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(147);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(148);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != '.' &&
+          (lookahead < '0' || '9' < lookahead) &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(sym_rawcode);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(sym_rawcode);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '+' ||
+          lookahead == '-') ADVANCE(154);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(162);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '+' || '-' < lookahead) &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(174);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(174);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(sym_rawcode);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(sym_rawcode);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(sym_rawcode);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(175);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          lookahead != 'E' &&
+          lookahead != 'e') ADVANCE(130);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(sym_boolean);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead == '\'') ADVANCE(176);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '\'' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(174);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(130);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(sym_boolean);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(sym_rawcode);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(131);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ',') ADVANCE(130);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 178:
+      if (eof) ADVANCE(184);
+      if (lookahead == '\n') ADVANCE(180);
+      if (lookahead == ']') ADVANCE(181);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(179);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(178);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym_name);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(183);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(182);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(sym_name);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(183);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(182);
+      END_STATE();
+    case 183:
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(183);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != ']') ADVANCE(182);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 185:
+      if (eof) ADVANCE(188);
+      if (lookahead == '\n') ADVANCE(186);
+      if (lookahead == ']') ADVANCE(187);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(185);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 189:
+      if (eof) ADVANCE(191);
+      if (lookahead == '\n') ADVANCE(190);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(189);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym__newline);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 192:
+      if (lookahead == '=') ADVANCE(193);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(192);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(anon_sym_EQ);
+      END_STATE();
+    case 194:
+      if (eof) ADVANCE(195);
+      if (lookahead == '\t' ||
+          (0x0b <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(194);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 103},
+  [2] = {.lex_state = 118},
+  [3] = {.lex_state = 118},
+  [4] = {.lex_state = 103},
+  [5] = {.lex_state = 118},
+  [6] = {.lex_state = 118},
+  [7] = {.lex_state = 118},
+  [8] = {.lex_state = 118},
+  [9] = {.lex_state = 118},
+  [10] = {.lex_state = 118},
+  [11] = {.lex_state = 118},
+  [12] = {.lex_state = 118},
+  [13] = {.lex_state = 118},
+  [14] = {.lex_state = 118},
+  [15] = {.lex_state = 118},
+  [16] = {.lex_state = 118},
+  [17] = {.lex_state = 118},
+  [18] = {.lex_state = 103},
+  [19] = {.lex_state = 103},
+  [20] = {.lex_state = 103},
+  [21] = {.lex_state = 103},
+  [22] = {.lex_state = 178},
+  [23] = {.lex_state = 185},
+  [24] = {.lex_state = 189},
+  [25] = {.lex_state = 189},
+  [26] = {.lex_state = 189},
+  [27] = {.lex_state = 189},
+  [28] = {.lex_state = 189},
+  [29] = {.lex_state = 189},
+  [30] = {.lex_state = 189},
+  [31] = {.lex_state = 189},
+  [32] = {.lex_state = 189},
+  [33] = {.lex_state = 192},
+  [34] = {.lex_state = 194},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [sym_comment] = ACTIONS(1),
+    [sym_key] = ACTIONS(1),
+    [sym_name] = ACTIONS(1),
+    [sym_number] = ACTIONS(1),
+    [sym_boolean] = ACTIONS(1),
+    [sym_rawcode] = ACTIONS(1),
+    [sym_string] = ACTIONS(1),
+    [sym_text] = ACTIONS(1),
+    [sym__newline] = ACTIONS(1),
+  },
+  [1] = {
+    [sym_document] = STATE(34),
+    [sym__entry] = STATE(25),
+    [sym_section] = STATE(26),
+    [sym_item] = STATE(27),
+    [aux_sym_document_repeat1] = STATE(4),
+    [ts_builtin_sym_end] = ACTIONS(3),
+    [anon_sym_LBRACK] = ACTIONS(5),
+    [anon_sym_EQ] = ACTIONS(7),
+    [sym_comment] = ACTIONS(9),
+    [sym_key] = ACTIONS(11),
+    [sym__newline] = ACTIONS(13),
+  },
+  [2] = {
+    [sym_value] = STATE(29),
+    [aux_sym_value_repeat1] = STATE(11),
+    [ts_builtin_sym_end] = ACTIONS(15),
+    [anon_sym_COMMA] = ACTIONS(17),
+    [sym_number] = ACTIONS(19),
+    [sym_boolean] = ACTIONS(21),
+    [sym_rawcode] = ACTIONS(23),
+    [sym_string] = ACTIONS(25),
+    [sym_text] = ACTIONS(27),
+    [sym__newline] = ACTIONS(29),
+  },
+  [3] = {
+    [sym_value] = STATE(32),
+    [aux_sym_value_repeat1] = STATE(11),
+    [ts_builtin_sym_end] = ACTIONS(31),
+    [anon_sym_COMMA] = ACTIONS(17),
+    [sym_number] = ACTIONS(19),
+    [sym_boolean] = ACTIONS(21),
+    [sym_rawcode] = ACTIONS(23),
+    [sym_string] = ACTIONS(25),
+    [sym_text] = ACTIONS(27),
+    [sym__newline] = ACTIONS(33),
+  },
+  [4] = {
+    [sym__entry] = STATE(30),
+    [sym_section] = STATE(26),
+    [sym_item] = STATE(27),
+    [ts_builtin_sym_end] = ACTIONS(35),
+    [anon_sym_LBRACK] = ACTIONS(5),
+    [anon_sym_EQ] = ACTIONS(7),
+    [sym_comment] = ACTIONS(9),
+    [sym_key] = ACTIONS(11),
+    [sym__newline] = ACTIONS(37),
+  },
+  [5] = {
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [sym_number] = ACTIONS(41),
+    [sym_boolean] = ACTIONS(41),
+    [sym_rawcode] = ACTIONS(41),
+    [sym_string] = ACTIONS(41),
+    [sym_text] = ACTIONS(41),
+    [sym__newline] = ACTIONS(41),
+  },
+  [6] = {
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [sym_number] = ACTIONS(41),
+    [sym_boolean] = ACTIONS(41),
+    [sym_rawcode] = ACTIONS(41),
+    [sym_string] = ACTIONS(41),
+    [sym_text] = ACTIONS(41),
+    [sym__newline] = ACTIONS(41),
+  },
+  [7] = {
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [sym_number] = ACTIONS(41),
+    [sym_boolean] = ACTIONS(41),
+    [sym_rawcode] = ACTIONS(41),
+    [sym_string] = ACTIONS(41),
+    [sym_text] = ACTIONS(41),
+    [sym__newline] = ACTIONS(41),
+  },
+  [8] = {
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [sym_number] = ACTIONS(41),
+    [sym_boolean] = ACTIONS(41),
+    [sym_rawcode] = ACTIONS(41),
+    [sym_string] = ACTIONS(41),
+    [sym_text] = ACTIONS(41),
+    [sym__newline] = ACTIONS(41),
+  },
+  [9] = {
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [sym_number] = ACTIONS(41),
+    [sym_boolean] = ACTIONS(41),
+    [sym_rawcode] = ACTIONS(41),
+    [sym_string] = ACTIONS(41),
+    [sym_text] = ACTIONS(41),
+    [sym__newline] = ACTIONS(41),
+  },
+  [10] = {
+    [ts_builtin_sym_end] = ACTIONS(39),
+    [anon_sym_COMMA] = ACTIONS(39),
+    [sym_number] = ACTIONS(41),
+    [sym_boolean] = ACTIONS(41),
+    [sym_rawcode] = ACTIONS(41),
+    [sym_string] = ACTIONS(41),
+    [sym_text] = ACTIONS(41),
+    [sym__newline] = ACTIONS(41),
+  },
+  [11] = {
+    [ts_builtin_sym_end] = ACTIONS(43),
+    [anon_sym_COMMA] = ACTIONS(45),
+    [sym_number] = ACTIONS(47),
+    [sym_boolean] = ACTIONS(49),
+    [sym_rawcode] = ACTIONS(51),
+    [sym_string] = ACTIONS(53),
+    [sym_text] = ACTIONS(55),
+    [sym__newline] = ACTIONS(57),
+  },
+  [12] = {
+    [ts_builtin_sym_end] = ACTIONS(59),
+    [anon_sym_COMMA] = ACTIONS(59),
+    [sym_number] = ACTIONS(61),
+    [sym_boolean] = ACTIONS(61),
+    [sym_rawcode] = ACTIONS(61),
+    [sym_string] = ACTIONS(61),
+    [sym_text] = ACTIONS(61),
+    [sym__newline] = ACTIONS(61),
+  },
+  [13] = {
+    [ts_builtin_sym_end] = ACTIONS(59),
+    [anon_sym_COMMA] = ACTIONS(59),
+    [sym_number] = ACTIONS(61),
+    [sym_boolean] = ACTIONS(61),
+    [sym_rawcode] = ACTIONS(61),
+    [sym_string] = ACTIONS(61),
+    [sym_text] = ACTIONS(61),
+    [sym__newline] = ACTIONS(61),
+  },
+  [14] = {
+    [ts_builtin_sym_end] = ACTIONS(59),
+    [anon_sym_COMMA] = ACTIONS(59),
+    [sym_number] = ACTIONS(61),
+    [sym_boolean] = ACTIONS(61),
+    [sym_rawcode] = ACTIONS(61),
+    [sym_string] = ACTIONS(61),
+    [sym_text] = ACTIONS(61),
+    [sym__newline] = ACTIONS(61),
+  },
+  [15] = {
+    [ts_builtin_sym_end] = ACTIONS(59),
+    [anon_sym_COMMA] = ACTIONS(59),
+    [sym_number] = ACTIONS(61),
+    [sym_boolean] = ACTIONS(61),
+    [sym_rawcode] = ACTIONS(61),
+    [sym_string] = ACTIONS(61),
+    [sym_text] = ACTIONS(61),
+    [sym__newline] = ACTIONS(61),
+  },
+  [16] = {
+    [ts_builtin_sym_end] = ACTIONS(59),
+    [anon_sym_COMMA] = ACTIONS(59),
+    [sym_number] = ACTIONS(61),
+    [sym_boolean] = ACTIONS(61),
+    [sym_rawcode] = ACTIONS(61),
+    [sym_string] = ACTIONS(61),
+    [sym_text] = ACTIONS(61),
+    [sym__newline] = ACTIONS(61),
+  },
+  [17] = {
+    [ts_builtin_sym_end] = ACTIONS(59),
+    [anon_sym_COMMA] = ACTIONS(59),
+    [sym_number] = ACTIONS(61),
+    [sym_boolean] = ACTIONS(61),
+    [sym_rawcode] = ACTIONS(61),
+    [sym_string] = ACTIONS(61),
+    [sym_text] = ACTIONS(61),
+    [sym__newline] = ACTIONS(61),
+  },
+};
+
+static const uint16_t ts_small_parse_table[] = {
+  [0] = 2,
+    ACTIONS(63), 3,
+      ts_builtin_sym_end,
+      anon_sym_LBRACK,
+      anon_sym_EQ,
+    ACTIONS(65), 3,
+      sym_comment,
+      sym_key,
+      sym__newline,
+  [11] = 2,
+    ACTIONS(67), 3,
+      ts_builtin_sym_end,
+      anon_sym_LBRACK,
+      anon_sym_EQ,
+    ACTIONS(69), 3,
+      sym_comment,
+      sym_key,
+      sym__newline,
+  [22] = 2,
+    ACTIONS(67), 3,
+      ts_builtin_sym_end,
+      anon_sym_LBRACK,
+      anon_sym_EQ,
+    ACTIONS(69), 3,
+      sym_comment,
+      sym_key,
+      sym__newline,
+  [33] = 2,
+    ACTIONS(71), 3,
+      ts_builtin_sym_end,
+      anon_sym_LBRACK,
+      anon_sym_EQ,
+    ACTIONS(73), 3,
+      sym_comment,
+      sym_key,
+      sym__newline,
+  [44] = 4,
+    ACTIONS(75), 1,
+      ts_builtin_sym_end,
+    ACTIONS(77), 1,
+      anon_sym_RBRACK,
+    ACTIONS(79), 1,
+      sym_name,
+    ACTIONS(81), 1,
+      sym__newline,
+  [57] = 3,
+    ACTIONS(83), 1,
+      ts_builtin_sym_end,
+    ACTIONS(85), 1,
+      anon_sym_RBRACK,
+    ACTIONS(87), 1,
+      sym__newline,
+  [67] = 2,
+    ACTIONS(89), 1,
+      ts_builtin_sym_end,
+    ACTIONS(91), 1,
+      sym__newline,
+  [74] = 2,
+    ACTIONS(35), 1,
+      ts_builtin_sym_end,
+    ACTIONS(93), 1,
+      sym__newline,
+  [81] = 2,
+    ACTIONS(89), 1,
+      ts_builtin_sym_end,
+    ACTIONS(91), 1,
+      sym__newline,
+  [88] = 2,
+    ACTIONS(89), 1,
+      ts_builtin_sym_end,
+    ACTIONS(91), 1,
+      sym__newline,
+  [95] = 2,
+    ACTIONS(95), 1,
+      ts_builtin_sym_end,
+    ACTIONS(97), 1,
+      sym__newline,
+  [102] = 2,
+    ACTIONS(99), 1,
+      ts_builtin_sym_end,
+    ACTIONS(101), 1,
+      sym__newline,
+  [109] = 2,
+    ACTIONS(103), 1,
+      ts_builtin_sym_end,
+    ACTIONS(105), 1,
+      sym__newline,
+  [116] = 2,
+    ACTIONS(107), 1,
+      ts_builtin_sym_end,
+    ACTIONS(109), 1,
+      sym__newline,
+  [123] = 2,
+    ACTIONS(111), 1,
+      ts_builtin_sym_end,
+    ACTIONS(113), 1,
+      sym__newline,
+  [130] = 1,
+    ACTIONS(115), 1,
+      anon_sym_EQ,
+  [134] = 1,
+    ACTIONS(117), 1,
+      ts_builtin_sym_end,
+};
+
+static const uint32_t ts_small_parse_table_map[] = {
+  [SMALL_STATE(18)] = 0,
+  [SMALL_STATE(19)] = 11,
+  [SMALL_STATE(20)] = 22,
+  [SMALL_STATE(21)] = 33,
+  [SMALL_STATE(22)] = 44,
+  [SMALL_STATE(23)] = 57,
+  [SMALL_STATE(24)] = 67,
+  [SMALL_STATE(25)] = 74,
+  [SMALL_STATE(26)] = 81,
+  [SMALL_STATE(27)] = 88,
+  [SMALL_STATE(28)] = 95,
+  [SMALL_STATE(29)] = 102,
+  [SMALL_STATE(30)] = 109,
+  [SMALL_STATE(31)] = 116,
+  [SMALL_STATE(32)] = 123,
+  [SMALL_STATE(33)] = 130,
+  [SMALL_STATE(34)] = 134,
+};
+
+static const TSParseActionEntry ts_parse_actions[] = {
+  [0] = {.entry = {.count = 0, .reusable = false}},
+  [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
+  [3] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 0, 0, 0),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(33),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(18),
+  [15] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_item, 1, 0, 0),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
+  [29] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_item, 1, 0, 0),
+  [31] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_item, 2, 0, 3),
+  [33] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_item, 2, 0, 3),
+  [35] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 1, 0, 0),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [39] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_value_repeat1, 1, 0, 0),
+  [41] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_value_repeat1, 1, 0, 0),
+  [43] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_value, 1, 0, 0),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(14),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [57] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_value, 1, 0, 0),
+  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_value_repeat1, 2, 0, 0),
+  [61] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_value_repeat1, 2, 0, 0),
+  [63] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 1, 0, 0),
+  [65] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 1, 0, 0),
+  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
+  [69] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 2, 0, 0),
+  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_document_repeat1, 3, 0, 0),
+  [73] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_document_repeat1, 3, 0, 0),
+  [75] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_section, 1, 0, 0),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
+  [81] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_section, 1, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_section, 2, 0, 1),
+  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_section, 2, 0, 1),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__entry, 1, 0, 0),
+  [91] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__entry, 1, 0, 0),
+  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(19),
+  [95] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_section, 2, 0, 0),
+  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_section, 2, 0, 0),
+  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_item, 2, 0, 4),
+  [101] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_item, 2, 0, 4),
+  [103] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_document, 2, 0, 0),
+  [105] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_section, 3, 0, 1),
+  [109] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_section, 3, 0, 1),
+  [111] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_item, 3, 0, 2),
+  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_item, 3, 0, 2),
+  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [117] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#ifdef TREE_SITTER_HIDE_SYMBOLS
+#define TS_PUBLIC
+#elif defined(_WIN32)
+#define TS_PUBLIC __declspec(dllexport)
+#else
+#define TS_PUBLIC __attribute__((visibility("default")))
+#endif
+
+TS_PUBLIC const TSLanguage *tree_sitter_bni(void) {
+  static const TSLanguage language = {
+    .version = LANGUAGE_VERSION,
+    .symbol_count = SYMBOL_COUNT,
+    .alias_count = ALIAS_COUNT,
+    .token_count = TOKEN_COUNT,
+    .external_token_count = EXTERNAL_TOKEN_COUNT,
+    .state_count = STATE_COUNT,
+    .large_state_count = LARGE_STATE_COUNT,
+    .production_id_count = PRODUCTION_ID_COUNT,
+    .field_count = FIELD_COUNT,
+    .max_alias_sequence_length = MAX_ALIAS_SEQUENCE_LENGTH,
+    .parse_table = &ts_parse_table[0][0],
+    .small_parse_table = ts_small_parse_table,
+    .small_parse_table_map = ts_small_parse_table_map,
+    .parse_actions = ts_parse_actions,
+    .symbol_names = ts_symbol_names,
+    .field_names = ts_field_names,
+    .field_map_slices = ts_field_map_slices,
+    .field_map_entries = ts_field_map_entries,
+    .symbol_metadata = ts_symbol_metadata,
+    .public_symbol_map = ts_symbol_map,
+    .alias_map = ts_non_terminal_alias_map,
+    .alias_sequences = &ts_alias_sequences[0][0],
+    .lex_modes = ts_lex_modes,
+    .lex_fn = ts_lex,
+    .primary_state_ids = ts_primary_state_ids,
+  };
+  return &language;
+}
+#ifdef __cplusplus
+}
+#endif